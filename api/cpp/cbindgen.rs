@@ -813,7 +813,10 @@ fn gen_testing(
     include_dir: &Path,
     dependencies: &mut Vec<PathBuf>,
 ) -> anyhow::Result<()> {
-    let config = default_config();
+    let mut config = default_config();
+    config
+        .defines
+        .insert("feature = debug-counters".into(), "SLINT_FEATURE_TESTING_DEBUG_COUNTERS".into());
 
     let mut crate_dir = root_dir.to_owned();
     crate_dir.extend(["internal", "backends", "testing"].iter());
@@ -959,7 +962,7 @@ pub fn from_env() -> Self {
     };
 }
 
-declare_features! {interpreter backend_qt freestanding renderer_software renderer_skia experimental gettext testing}
+declare_features! {interpreter backend_qt freestanding renderer_software renderer_skia experimental gettext testing testing_debug_counters}
 
 /// Generate the headers.
 /// `root_dir` is the root directory of the slint git repo
@@ -11,6 +11,7 @@
 
 #    include "slint_interpreter_internal.h"
 
+#    include <iostream>
 #    include <optional>
 
 #    ifdef SLINT_FEATURE_BACKEND_QT
@@ -1062,6 +1063,76 @@ inline void send_keyboard_string_sequence(const slint::interpreter::ComponentIns
     cbindgen_private::send_keyboard_string_sequence(
             &str, reinterpret_cast<const cbindgen_private::WindowAdapterRc *>(win_ptr));
 }
+
+/// Returns the type of the property \a property of the element declared with the id
+/// \a element_id in \a component, or an empty optional if the element or the property doesn't
+/// exist.
+///
+/// The id must be qualified with the name of the component it was declared in, for example
+/// `MyComponent::my-button`. The root element of a component has the id `MyComponent::root`.
+inline std::optional<slint::interpreter::Value::Type>
+element_property_type(const slint::interpreter::ComponentInstance *component,
+                      std::string_view element_id, std::string_view property)
+{
+    slint::interpreter::Value::Type type;
+    if (!cbindgen_private::slint_interpreter_testing_element_property_type(
+                reinterpret_cast<const cbindgen_private::ErasedItemTreeBox *>(component),
+                slint::private_api::string_to_slice(element_id),
+                slint::private_api::string_to_slice(property), &type)) {
+        return {};
+    }
+    return type;
+}
+
+/// Aborts if the property \a property of the element declared with the id \a element_id in
+/// \a component doesn't exist or doesn't have the type \a expected.
+///
+/// The id must be qualified like for element_property_type().
+inline void assert_property_type(const slint::interpreter::ComponentInstance *component,
+                                 std::string_view element_id, std::string_view property,
+                                 slint::interpreter::Value::Type expected)
+{
+    auto type = element_property_type(component, element_id, property);
+    if (!type) {
+        std::cerr << "assert_property_type FAILED: " << element_id << " has no property "
+                  << property << std::endl;
+        std::abort();
+    }
+    if (*type != expected) {
+        std::cerr << "assert_property_type FAILED: " << element_id << "." << property
+                  << " has the type " << int(*type) << ", expected " << int(expected) << std::endl;
+        std::abort();
+    }
+}
+
+/// Compiles \a source with a new slint::interpreter::ComponentCompiler and returns the errors
+/// that the compiler reports. Warnings are not included.
+inline std::vector<slint::interpreter::Diagnostic> compile_errors(std::string_view source)
+{
+    slint::interpreter::ComponentCompiler compiler;
+    compiler.build_from_source(source, "");
+    std::vector<slint::interpreter::Diagnostic> errors;
+    for (const auto &diagnostic : compiler.diagnostics()) {
+        if (diagnostic.level == slint::interpreter::DiagnosticLevel::Error) {
+            errors.push_back(diagnostic);
+        }
+    }
+    return errors;
+}
+
+/// Compiles \a source with a new slint::interpreter::ComponentCompiler and aborts if the compiler
+/// reports an error. Warnings are allowed.
+inline void assert_no_compile_errors(std::string_view source)
+{
+    auto errors = compile_errors(source);
+    for (const auto &error : errors) {
+        std::cerr << "assert_no_compile_errors FAILED: " << error.line << ":" << error.column
+                  << ": " << error.message << std::endl;
+    }
+    if (!errors.empty()) {
+        std::abort();
+    }
+}
 }
 
 #endif
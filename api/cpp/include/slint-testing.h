@@ -3,11 +3,70 @@
 
 #include "slint.h"
 #include "slint_testing_internal.h"
+#include <algorithm>
+#include <chrono>
+#include <cmath>
+#include <cstdlib>
+#include <functional>
+#include <iostream>
+#include <memory>
 #include <optional>
 #include <string_view>
+#include <type_traits>
+#include <utility>
+#include <vector>
 
 #ifdef SLINT_FEATURE_TESTING
 
+#if defined(__has_feature)
+#    if __has_feature(address_sanitizer)
+#        define SLINT_TESTING_LEAK_SANITIZER
+#    endif
+#elif defined(__SANITIZE_ADDRESS__)
+#    define SLINT_TESTING_LEAK_SANITIZER
+#endif
+
+#ifdef SLINT_TESTING_LEAK_SANITIZER
+extern "C" int __lsan_do_recoverable_leak_check();
+#endif
+
+namespace slint::testing {
+class ElementHandle;
+}
+
+namespace slint::private_api {
+/// Gives the testing helpers access to the item that a slint::testing::ElementHandle refers to.
+struct ElementHandleAccess
+{
+    static const cbindgen_private::ItemWeak &inner(const testing::ElementHandle &element);
+};
+
+/// Parses the string representation of a number, such as the value of the accessible
+/// properties.
+inline std::optional<float> string_to_float(const SharedString &str)
+{
+    char *end = nullptr;
+    float value = std::strtof(str.data(), &end);
+    if (end == str.data() || *end != '\0') {
+        return std::nullopt;
+    }
+    return value;
+}
+
+/// Returns the data of all the rows of \a model, in order.
+template<typename ModelData>
+std::vector<ModelData> model_rows(const std::shared_ptr<Model<ModelData>> &model)
+{
+    std::vector<ModelData> rows;
+    for (std::size_t row = 0; row < model->row_count(); ++row) {
+        if (auto data = model->row_data(row)) {
+            rows.push_back(*data);
+        }
+    }
+    return rows;
+}
+}
+
 namespace slint::testing {
 /// Init the testing backend.
 /// Should be called before any other Slint function that can access the platform.
@@ -17,13 +76,467 @@ inline void init()
     cbindgen_private::slint_testing_init_backend();
 }
 
+/// Runs \a test_body and returns the number of frames that were rendered for the window
+/// of \a component while doing so.
+///
+/// The testing backend doesn't produce any pixels, but it visits the elements of a window like
+/// a real renderer when a redraw was requested, for example because a property that affects the
+/// appearance of an element changed. A redraw that is still pending after \a test_body returns
+/// is rendered and counted. A redraw that was already pending before is rendered beforehand and
+/// not counted.
+template<typename T>
+uint32_t with_render_counter(const ComponentHandle<T> &component, std::function<void()> test_body)
+{
+    const auto &window = component->window().window_handle();
+    cbindgen_private::slint_testing_window_draw_if_needed(&window);
+    auto start = cbindgen_private::slint_testing_window_rendered_frames(&window);
+    test_body();
+    cbindgen_private::slint_testing_window_draw_if_needed(&window);
+    return cbindgen_private::slint_testing_window_rendered_frames(&window) - start;
+}
+
+/// Runs \a test_body and aborts if more than \a max_passes frames were rendered for the window of
+/// \a component while doing so.
+///
+/// See with_render_counter() for how the frames are counted. Pass 0 to verify that a change
+/// doesn't affect the rendering at all.
+template<typename T>
+void assert_render_passes_count(const ComponentHandle<T> &component, uint32_t max_passes,
+                                std::function<void()> test_body)
+{
+    auto passes = with_render_counter(component, std::move(test_body));
+    if (passes > max_passes) {
+        std::cerr << "assert_render_passes_count FAILED: the change caused " << passes
+                  << " render passes, expected at most " << max_passes << std::endl;
+        std::abort();
+    }
+}
+
+/// Returns an estimate of the number of layers that a GPU renderer composes to render the window
+/// of \a component.
+///
+/// The testing backend doesn't use the GPU, and the renderers don't report the layers they
+/// create, so this counts the elements of the window that are likely to need a separate layer:
+/// elements with an `opacity` below 1 that have several children or grandchildren, elements with
+/// `cache-rendering-hint: true`, and elements that clip their children with a border radius.
+/// A renderer may create fewer layers, for example when it can draw such an element directly.
+template<typename T>
+uint32_t count_compositing_layers(const ComponentHandle<T> &component)
+{
+    return cbindgen_private::slint_testing_window_compositing_layers(
+            &component->window().window_handle());
+}
+
+/// Calls \a create to construct a component, renders the first frame of its window, and returns
+/// the component handle together with the time that elapsed from the start of the construction
+/// until that frame was rendered.
+///
+/// The testing backend doesn't produce any pixels, so the measured time covers the instantiation
+/// of the component, the first evaluation of the bindings and the layouts, and the traversal of
+/// the elements by the renderer.
+template<typename F>
+auto measure_first_frame_time(F create)
+        -> std::pair<decltype(create()), std::chrono::duration<double>>
+{
+    private_api::assert_main_thread();
+    auto start = std::chrono::steady_clock::now();
+    auto component = create();
+    const auto &window = component->window().window_handle();
+    window.request_redraw();
+    cbindgen_private::slint_testing_window_draw_if_needed(&window);
+    std::chrono::duration<double> elapsed = std::chrono::steady_clock::now() - start;
+    return { std::move(component), elapsed };
+}
+
+/// Advances the time of the animations by \a time_ms milliseconds, and aborts if the value that
+/// \a value reads from an animated property then doesn't match \a expected, within 0.01.
+///
+/// Slint animations don't run in real time in tests, so the value of a property that is being
+/// animated only changes when the mocked time advances. Read the property once before changing
+/// the properties it depends on, so that the animation starts at the time of the change.
+inline void assert_animation_value(float expected, uint64_t time_ms, std::function<float()> value)
+{
+    cbindgen_private::slint_mock_elapsed_time(time_ms);
+    auto actual = value();
+    if (std::abs(actual - expected) > 0.01f) {
+        std::cerr << "assert_animation_value FAILED: after " << time_ms
+                  << "ms, the animated value is " << actual << ", expected " << expected
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Runs \a test_body and aborts if a window that was created while doing so still exists
+/// afterwards.
+///
+/// A component owns the window of the testing backend that it is shown in, so a window that
+/// outlives \a test_body means that its component was leaked, typically because of a reference
+/// cycle between the component and one of its callbacks. Use a ComponentWeakHandle to access the
+/// component from its own callbacks. Components create their window lazily, the first time it is
+/// needed, and a window that is visible keeps its component alive until hide() is called.
+///
+/// When the test is built with AddressSanitizer, this also runs the leak checker of the sanitizer,
+/// which reports all leaked allocations, not only the ones of Slint.
+inline void with_leak_check(std::function<void()> test_body)
+{
+    auto start = cbindgen_private::slint_testing_live_window_count();
+    test_body();
+    auto live = cbindgen_private::slint_testing_live_window_count();
+    if (live > start) {
+        std::cerr << "with_leak_check FAILED: " << (live - start)
+                  << " window(s) created during the leak check still exist: a component leaked"
+                  << std::endl;
+        std::abort();
+    }
+#ifdef SLINT_TESTING_LEAK_SANITIZER
+    if (__lsan_do_recoverable_leak_check() != 0) {
+        std::cerr << "with_leak_check FAILED: the leak sanitizer found leaks" << std::endl;
+        std::abort();
+    }
+#endif
+}
+
+/// Overrides the color scheme that the system reports for the window of \a component.
+///
+/// Styles pick their colors based on that scheme, unless the application chose a scheme
+/// explicitly, for example through `Palette.color-scheme`.
+template<typename T>
+void set_system_color_scheme(const ComponentHandle<T> &component,
+                             cbindgen_private::ColorScheme scheme)
+{
+    cbindgen_private::slint_testing_window_set_color_scheme(&component->window().window_handle(),
+                                                            scheme);
+}
+
+/// Simulates the user resizing the window of \a component to \a new_width x \a new_height
+/// logical pixels.
+///
+/// The window receives the same resize event as with a real windowing system, so that the
+/// `width` and `height` of the `Window` element, and the layouts that depend on them, are updated.
+template<typename T>
+void simulate_window_resize(const ComponentHandle<T> &component, float new_width, float new_height)
+{
+    component->window().set_size(LogicalSize({ new_width, new_height }));
+}
+
+/// Resizes the window of \a component to \a new_width x \a new_height logical pixels, and
+/// returns the time that it takes to compute the layouts of its elements for that size.
+///
+/// Layouts are computed lazily, when the geometry of an element is needed, so the measured time
+/// covers the resize and the computation of the geometry of all the elements of the window, but
+/// not the rendering. If the window already has that size, or if its size is fixed, the layouts
+/// aren't computed again.
+template<typename T>
+std::chrono::duration<double> measure_layout_time(const ComponentHandle<T> &component,
+                                                  float new_width, float new_height)
+{
+    auto start = std::chrono::steady_clock::now();
+    simulate_window_resize(component, new_width, new_height);
+    cbindgen_private::slint_testing_window_compute_layouts(&component->window().window_handle());
+    return std::chrono::steady_clock::now() - start;
+}
+
+#if defined(SLINT_FEATURE_TESTING_DEBUG_COUNTERS) || defined(DOXYGEN)
+/// Calls \a mutator to change some properties, and returns how many layouts of the window of
+/// \a component had to be solved again because of that change.
+///
+/// Layouts are solved lazily, when the geometry of an element is needed, so the layouts are solved
+/// before calling \a mutator, and then the geometry of all the elements of the window is computed
+/// after it. Each layout counts once per orientation that it had to solve, and a `GridLayout`
+/// always solves both at the same time.
+///
+/// This requires the `SLINT_FEATURE_TESTING_DEBUG_COUNTERS` option.
+template<typename T>
+uint32_t count_layout_passes(const ComponentHandle<T> &component,
+                             const std::function<void()> &mutator)
+{
+    const auto &window = component->window().window_handle();
+    cbindgen_private::slint_testing_window_compute_layouts(&window);
+    auto start = cbindgen_private::slint_testing_layout_solve_count();
+    mutator();
+    cbindgen_private::slint_testing_window_compute_layouts(&window);
+    return cbindgen_private::slint_testing_layout_solve_count() - start;
+}
+
+/// Calls \a mutator to change some properties, and aborts if more than \a max_passes layouts of
+/// the window of \a component had to be solved again because of that change.
+///
+/// See count_layout_passes() for how the layouts are counted.
+template<typename T>
+void assert_layout_passes_count(const ComponentHandle<T> &component, uint32_t max_passes,
+                                const std::function<void()> &mutator)
+{
+    auto passes = count_layout_passes(component, mutator);
+    if (passes > max_passes) {
+        std::cerr << "assert_layout_passes_count FAILED: the change caused " << passes
+                  << " layout passes, expected at most " << max_passes << std::endl;
+        std::abort();
+    }
+}
+#endif
+
+/// Injects \a event into the window of \a component, as if it came from the windowing system.
+///
+/// This is useful to test how the application reacts to events that the user cannot trigger
+/// from within the window. For example, Slint has no separate event for an application that is
+/// paused and resumed: the platform reports it by deactivating and re-activating the window with
+/// a `WindowActiveChanged` event, which also removes and restores the keyboard focus.
+template<typename T>
+void simulate_platform_event(const ComponentHandle<T> &component,
+                             const cbindgen_private::WindowEvent &event)
+{
+    private_api::assert_main_thread();
+    cbindgen_private::slint_windowrc_dispatch_event(&component->window().window_handle().handle(),
+                                                   &event);
+}
+
+/// Simulates the low-memory warning that mobile platforms send to applications that should
+/// release their caches.
+///
+/// Slint doesn't notify applications of such warnings, so this function releases the caches that
+/// Slint maintains itself: the cache of decoded images is cleared and the window of \a component
+/// is redrawn, so that the elements that are visible load again the resources that they need.
+template<typename T>
+void simulate_low_memory_warning(const ComponentHandle<T> &component)
+{
+    private_api::assert_main_thread();
+    cbindgen_private::slint_testing_clear_image_cache();
+    component->window().request_redraw();
+}
+
+/// Aborts if the title of the window of \a component isn't \a expected.
+///
+/// The title is the one that Slint passes on to the windowing system, as set with the `title`
+/// property of the `Window` element.
+template<typename T>
+void assert_window_title(const ComponentHandle<T> &component, std::string_view expected)
+{
+    SharedString title;
+    if (!cbindgen_private::slint_testing_window_title(&component->window().window_handle(),
+                                                      &title)) {
+        std::cerr << "assert_window_title FAILED: the window wasn't created by the testing backend"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::string_view(title) != expected) {
+        std::cerr << "assert_window_title FAILED: the window title is \"" << title
+                  << "\", expected \"" << expected << "\"" << std::endl;
+        std::abort();
+    }
+}
+
+/// An empty in-memory clipboard that replaces the clipboard of the testing backend until it is
+/// destroyed. Obtain it with install_mock_clipboard().
+///
+/// Elements such as `TextInput` copy to and paste from that clipboard, so that tests can check
+/// what was copied, or provide the text to paste. The clipboard of the testing backend is left
+/// untouched, and is used again once the MockClipboard is destroyed. If several mock clipboards
+/// are installed, the last one that wasn't destroyed is used.
+class MockClipboard
+{
+    void *inner = cbindgen_private::slint_testing_install_mock_clipboard();
+
+    MockClipboard() = default;
+    friend MockClipboard install_mock_clipboard();
+
+public:
+    MockClipboard(const MockClipboard &) = delete;
+    MockClipboard &operator=(const MockClipboard &) = delete;
+    ~MockClipboard() { cbindgen_private::slint_testing_mock_clipboard_drop(inner); }
+
+    /// Returns the text in the clipboard, if any.
+    std::optional<SharedString> text() const
+    {
+        SharedString result;
+        if (cbindgen_private::slint_testing_mock_clipboard_text(inner, &result)) {
+            return result;
+        }
+        return std::nullopt;
+    }
+
+    /// Puts \a text into the clipboard, as if it was copied from another application.
+    void set_text(const SharedString &text)
+    {
+        cbindgen_private::slint_testing_mock_clipboard_set_text(inner, &text);
+    }
+
+    /// Empties the clipboard.
+    void clear() { cbindgen_private::slint_testing_mock_clipboard_set_text(inner, nullptr); }
+};
+
+/// Replaces the clipboard of the testing backend with an empty one, until the returned
+/// MockClipboard is destroyed.
+inline MockClipboard install_mock_clipboard()
+{
+    return MockClipboard();
+}
+
+/// Puts \a text into the mock clipboard that was last installed with install_mock_clipboard(),
+/// as if it was copied from another application.
+///
+/// This is the same as MockClipboard::set_text(), for the parts of a test that don't have access
+/// to the MockClipboard. Aborts if no mock clipboard is installed.
+inline void inject_clipboard_text(const SharedString &text)
+{
+    if (!cbindgen_private::slint_testing_inject_clipboard_text(&text)) {
+        std::cerr << "inject_clipboard_text FAILED: no mock clipboard is installed, call "
+                     "install_mock_clipboard() first"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Simulates the user pressing the keys of a shortcut such as Ctrl+S in the window of
+/// \a component.
+///
+/// The \a keys are pressed in order, and then released in the reverse order, so that modifiers
+/// stay pressed until the last key was released. Each key is either a character or one of the
+/// constants in slint::platform::key_codes.
+template<typename T>
+void simulate_key_sequence(const ComponentHandle<T> &component, std::vector<SharedString> keys)
+{
+    auto &window = component->window();
+    for (const auto &key : keys) {
+        window.dispatch_key_press_event(key);
+    }
+    for (auto it = keys.rbegin(); it != keys.rend(); ++it) {
+        window.dispatch_key_release_event(*it);
+    }
+}
+
+/// Simulates pressing the Tab key once for each entry of \a element_ids, and aborts if the
+/// keyboard focus doesn't move to the element with that id.
+///
+/// The ids must be qualified with the name of the component, as in
+/// ElementHandle::find_by_element_id(). An element also counts as focused when the focus is on
+/// one of its children, so that this function can be used with widgets such as `LineEdit`.
+template<typename T>
+void assert_tab_order(const ComponentHandle<T> &component, std::vector<SharedString> element_ids)
+{
+    const auto &window = component->window().window_handle();
+    for (std::size_t press = 0; press < element_ids.size(); ++press) {
+        simulate_key_sequence(component, { platform::key_codes::Tab });
+        std::string_view element_id = element_ids[press];
+        cbindgen_private::Slice<uint8_t> element_id_view {
+            const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(element_id.data())),
+            element_id.size()
+        };
+        if (!cbindgen_private::slint_testing_window_focus_has_element_id(&window,
+                                                                          &element_id_view)) {
+            std::cerr << "assert_tab_order FAILED: after " << (press + 1)
+                      << " Tab key press(es), expected the focus on " << element_id << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Records the keys that are pressed while a `FocusScope` has the keyboard focus, or one of its
+/// children has the focus and doesn't accept the key. Obtain it with record_key_presses().
+///
+/// The key presses are recorded before the `key-pressed` callback of the `FocusScope` is invoked,
+/// whether or not that callback accepts them, and until the recorder is destroyed.
+class KeyPressRecorder
+{
+    std::shared_ptr<std::vector<SharedString>> recorded =
+            std::make_shared<std::vector<SharedString>>();
+    void *observer = nullptr;
+
+    explicit KeyPressRecorder(const ElementHandle &element);
+    friend KeyPressRecorder record_key_presses(const ElementHandle &element);
+
+public:
+    KeyPressRecorder(const KeyPressRecorder &) = delete;
+    KeyPressRecorder &operator=(const KeyPressRecorder &) = delete;
+    ~KeyPressRecorder() { cbindgen_private::slint_testing_drop_callback_observer(observer); }
+
+    /// Returns the text of the recorded key presses, in the order in which they were pressed.
+    std::vector<SharedString> keys() const { return *recorded; }
+
+    /// Returns how many times the key with the text \a key was pressed.
+    std::size_t count(const SharedString &key) const
+    {
+        return static_cast<std::size_t>(std::count(recorded->begin(), recorded->end(), key));
+    }
+
+    /// Forgets the key presses recorded so far.
+    void clear() { recorded->clear(); }
+};
+
+/// Counts how many times a callback of an element was emitted. Obtain it with record_signal().
+///
+/// The emissions are counted before the handler of the callback is invoked, and until the
+/// recorder is destroyed.
+class SignalRecorder
+{
+    std::shared_ptr<std::size_t> emitted = std::make_shared<std::size_t>(0);
+    void *observer = nullptr;
+
+    SignalRecorder(const ElementHandle &element, std::string_view name);
+    friend SignalRecorder record_signal(const ElementHandle &element, std::string_view name);
+
+public:
+    SignalRecorder(const SignalRecorder &) = delete;
+    SignalRecorder &operator=(const SignalRecorder &) = delete;
+    ~SignalRecorder() { cbindgen_private::slint_testing_drop_callback_observer(observer); }
+
+    /// Returns how many times the callback was emitted.
+    std::size_t count() const { return *emitted; }
+
+    /// Forgets the emissions counted so far.
+    void clear() { *emitted = 0; }
+};
+
 /// A Handle to an element to query accessible property for testing purposes.
 ///
-/// Use find_by_accessible_label() to obtain all elements matching the given accessible label.
+/// Use find_by_accessible_label() to obtain all elements matching the given accessible label,
+/// or find_by_element_id() to obtain all elements declared with the given id.
 class ElementHandle
 {
     cbindgen_private::ItemWeak inner;
 
+    friend struct private_api::ElementHandleAccess;
+
+    // Splits the debug information of an element into one line per element (see
+    // ItemRc::element_infos)
+    static std::vector<std::string_view> split_element_infos(std::string_view infos)
+    {
+        std::vector<std::string_view> lines;
+        std::string_view rest = infos;
+        while (!rest.empty()) {
+            auto pos = rest.find('\n');
+            lines.push_back(rest.substr(0, pos));
+            if (pos == std::string_view::npos)
+                break;
+            rest = rest.substr(pos + 1);
+        }
+        return lines;
+    }
+
+    std::optional<SharedString> item_element_infos() const
+    {
+        if (auto item = private_api::upgrade_item_weak(inner)) {
+            SharedString result;
+            if (item->item_tree.vtable()->item_element_infos(item->item_tree.borrow(), item->index,
+                                                             &result)) {
+                return result;
+            }
+        }
+        return std::nullopt;
+    }
+
+    std::optional<float>
+    accessible_float_property(cbindgen_private::AccessibleStringProperty what) const
+    {
+        if (auto item = private_api::upgrade_item_weak(inner)) {
+            SharedString result;
+            if (item->item_tree.vtable()->accessible_string_property(
+                        item->item_tree.borrow(), item->index, what, &result)) {
+                return private_api::string_to_float(result);
+            }
+        }
+        return std::nullopt;
+    }
+
 public:
     /// Find all elements matching the given accessible label.
     template<typename T>
@@ -42,9 +555,103 @@ public:
         return result;
     }
 
+    /// Find all elements that were declared with the given id in the Slint code.
+    ///
+    /// The id must be qualified with the name of the component it was declared in, for example
+    /// `MyComponent::my-button`. This requires the debug information to be generated by the
+    /// Slint compiler, which is enabled by setting the `SLINT_EMIT_DEBUG_INFO` environment
+    /// variable when compiling.
+    template<typename T>
+    static SharedVector<ElementHandle> find_by_element_id(const ComponentHandle<T> &component,
+                                                          std::string_view element_id)
+    {
+        cbindgen_private::Slice<uint8_t> element_id_view {
+            const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(element_id.data())),
+            element_id.size()
+        };
+        auto vrc = component.into_dyn();
+        SharedVector<ElementHandle> result;
+        cbindgen_private::slint_testing_element_find_by_element_id(
+                &vrc, &element_id_view,
+                reinterpret_cast<SharedVector<cbindgen_private::ItemWeak> *>(&result));
+        return result;
+    }
+
     /// Returns true if the underlying element still exists; false otherwise.
     bool is_valid() const { return private_api::upgrade_item_weak(inner).has_value(); }
 
+    /// Returns the id of the element qualified with the name of its component, for example
+    /// `MyComponent::my-button`, if the element has an id.
+    std::optional<SharedString> id() const
+    {
+        if (auto infos = item_element_infos()) {
+            for (auto line : split_element_infos(*infos)) {
+                if (auto pos = line.find(';'); pos != std::string_view::npos) {
+                    return SharedString(line.substr(pos + 1));
+                }
+            }
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the name of the type of the element as written in the Slint code, for example
+    /// `Rectangle` or `MyButton`.
+    std::optional<SharedString> type_name() const
+    {
+        if (auto infos = item_element_infos()) {
+            auto lines = split_element_infos(*infos);
+            if (!lines.empty()) {
+                return SharedString(lines.front().substr(0, lines.front().find(';')));
+            }
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the properties read by the binding of \a property of that element, in the form
+    /// `MyComponent::my-button.text`. Returns nullopt if the property has no binding, or if the
+    /// code wasn't compiled with debug information.
+    std::optional<SharedVector<SharedString>> property_dependencies(std::string_view property) const
+    {
+        if (auto item = private_api::upgrade_item_weak(inner)) {
+            SharedString name(property);
+            SharedVector<SharedString> result;
+            if (item->item_tree.vtable()->item_property_dependencies(
+                        item->item_tree.borrow(), item->index, &name, &result)) {
+                return result;
+            }
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the name of the state of that element that currently applies, as declared in its
+    /// `states` block, or an empty string if no state applies. Returns nullopt if the element has
+    /// no states, or if the code wasn't compiled with debug information.
+    std::optional<SharedString> current_state() const
+    {
+        if (auto item = private_api::upgrade_item_weak(inner)) {
+            SharedString result;
+            if (item->item_tree.vtable()->item_current_state(item->item_tree.borrow(), item->index,
+                                                             &result)) {
+                return result;
+            }
+        }
+        return std::nullopt;
+    }
+
+    /// Returns the accessible-role of that element, if the element is accessible.
+    std::optional<cbindgen_private::AccessibleRole> accessible_role() const
+    {
+        if (auto item = private_api::upgrade_item_weak(inner)) {
+            auto item_tree = item->item_tree.vtable()->get_item_tree(item->item_tree.borrow());
+            const auto &node = item_tree.ptr[item->index];
+            if (node.tag == private_api::ItemTreeNode::Tag::Item && node.item.is_accessible) {
+                return item->item_tree.vtable()->accessible_role(item->item_tree.borrow(),
+                                                                 item->index);
+            }
+        }
+        return std::nullopt;
+    }
+
     /// Returns the accessible-label of that element, if any.
     std::optional<SharedString> accessible_label() const
     {
@@ -87,6 +694,18 @@ public:
         return std::nullopt;
     }
 
+    /// Returns the accessible-value-minimum of that element, if any.
+    std::optional<float> accessible_value_minimum() const
+    {
+        return accessible_float_property(cbindgen_private::AccessibleStringProperty::ValueMinimum);
+    }
+
+    /// Returns the accessible-value-maximum of that element, if any.
+    std::optional<float> accessible_value_maximum() const
+    {
+        return accessible_float_property(cbindgen_private::AccessibleStringProperty::ValueMaximum);
+    }
+
     /// Returns the accessible-checked of that element, if any.
     std::optional<bool> accessible_checked() const
     {
@@ -170,7 +789,1415 @@ public:
         return LogicalPosition({ 0, 0 });
     }
 };
+}
+
+namespace slint::private_api {
+inline const cbindgen_private::ItemWeak &
+ElementHandleAccess::inner(const testing::ElementHandle &element)
+{
+    return element.inner;
+}
+}
+
+namespace slint::testing {
+/// Aborts if the number of instances of the repeated element declared with the id \a element_id
+/// in \a component isn't \a expected.
+///
+/// The element is the one that is repeated with a `for` (or instantiated with an `if`), and its
+/// id is qualified with the name of the component as in ElementHandle::find_by_element_id().
+/// The instances are counted by visiting the element tree of \a component, and visiting a
+/// repeater updates it first, so changes to its model since the last frame are taken into
+/// account without rendering.
+template<typename T>
+void assert_repeater_count(const ComponentHandle<T> &component, std::string_view element_id,
+                           std::size_t expected)
+{
+    auto count = ElementHandle::find_by_element_id(component, element_id).size();
+    if (count != expected) {
+        std::cerr << "assert_repeater_count FAILED: expected " << expected << " instance(s) of "
+                  << element_id << ", found " << count << std::endl;
+        std::abort();
+    }
+}
+
+/// Returns a text representation of the tree of elements of \a component, to help diagnosing the
+/// failure of a test.
+///
+/// Each element is on its own line, indented according to its depth in the tree, with the name
+/// of its type, its qualified ids as in ElementHandle::find_by_element_id(), and its position
+/// relative to its parent and its size, in logical pixels. The types and ids require the debug
+/// information to be generated by the Slint compiler. Without it, every element is shown as
+/// `Item`.
+template<typename T>
+SharedString dump_item_tree(const ComponentHandle<T> &component)
+{
+    auto vrc = component.into_dyn();
+    SharedString result;
+    cbindgen_private::slint_testing_dump_item_tree(&vrc, &result);
+    return result;
+}
+
+/// Returns the id of the topmost element at the position \a x, \a y of the window of
+/// \a component, in logical pixels, to test which element a click at that position would hit.
+///
+/// The id is qualified with the name of the component, as in ElementHandle::find_by_element_id().
+/// Elements without an id are skipped. Returns nullopt if no element with an id covers the
+/// position, or if the code wasn't compiled with debug information.
+template<typename T>
+std::optional<SharedString> element_id_at_position(const ComponentHandle<T> &component, float x,
+                                                   float y)
+{
+    auto vrc = component.into_dyn();
+    SharedString result;
+    if (cbindgen_private::slint_testing_element_id_at_position(&vrc, x, y, &result)) {
+        return result;
+    }
+    return std::nullopt;
+}
+
+/// Aborts if the topmost element at the position \a x, \a y of the window of \a component, in
+/// logical pixels, isn't the one with the id \a expected_id. See element_id_at_position() for how
+/// the element is found.
+template<typename T>
+void assert_element_at_position(const ComponentHandle<T> &component, float x, float y,
+                                std::string_view expected_id)
+{
+    auto id = element_id_at_position(component, x, y);
+    if (!id || std::string_view(*id) != expected_id) {
+        std::cerr << "assert_element_at_position FAILED: the element at (" << x << ", " << y
+                  << ") is " << (id ? std::string_view(*id) : "none") << ", expected "
+                  << expected_id << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the element with the id \a top_id isn't drawn above the element with the id
+/// \a bottom_id in the window of \a component.
+///
+/// The ids must be qualified with the name of the component, as in
+/// ElementHandle::find_by_element_id(). The elements are drawn in the order of the tree of
+/// elements: children above their parent, and later siblings above the earlier ones.
+template<typename T>
+void assert_z_order(const ComponentHandle<T> &component, std::string_view top_id,
+                    std::string_view bottom_id)
+{
+    auto vrc = component.into_dyn();
+    auto rendering_order = [&](std::string_view element_id) {
+        cbindgen_private::Slice<uint8_t> element_id_view {
+            const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(element_id.data())),
+            element_id.size()
+        };
+        std::size_t position = 0;
+        if (!cbindgen_private::slint_testing_rendering_order(&vrc, &element_id_view, &position)) {
+            std::cerr << "assert_z_order FAILED: no element with the id " << element_id
+                      << " was found" << std::endl;
+            std::abort();
+        }
+        return position;
+    };
+    if (rendering_order(top_id) <= rendering_order(bottom_id)) {
+        std::cerr << "assert_z_order FAILED: " << top_id << " is drawn below " << bottom_id
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Simulates the user moving the mouse over the center of \a element, and aborts if the mouse
+/// cursor of the window of \a component then isn't \a expected.
+///
+/// The cursor is the one that the element under the mouse requests, for example with the
+/// `mouse-cursor` property of a `TouchArea`, or the text cursor of a `TextInput`.
+template<typename T>
+void assert_cursor_shape(const ComponentHandle<T> &component, const ElementHandle &element,
+                         cbindgen_private::MouseCursor expected)
+{
+    auto position = element.absolute_position();
+    auto size = element.size();
+    component->window().dispatch_pointer_move_event(LogicalPosition(
+            { position.x + size.width / 2, position.y + size.height / 2 }));
+    auto cursor = cbindgen_private::slint_testing_window_mouse_cursor(
+            &component->window().window_handle());
+    if (cursor != expected) {
+        std::cerr << "assert_cursor_shape FAILED: the mouse cursor over the element is "
+                  << int(cursor) << ", expected " << int(expected) << std::endl;
+        std::abort();
+    }
+}
 
+/// Aborts if the `pressed` property of \a element is false.
+///
+/// The element is either a `TouchArea` or a widget that contains one, such as `Button`. It is
+/// pressed from the moment a mouse button is pressed over it, until that button is released.
+inline void assert_touch_area_pressed(const ElementHandle &element)
+{
+    bool pressed = false;
+    if (!cbindgen_private::slint_testing_touch_area_pressed(
+                &private_api::ElementHandleAccess::inner(element), &pressed)) {
+        std::cerr << "assert_touch_area_pressed FAILED: the element is not, and doesn't contain, "
+                     "a TouchArea"
+                  << std::endl;
+        std::abort();
+    }
+    if (!pressed) {
+        std::cerr << "assert_touch_area_pressed FAILED: the touch area is released, expected it "
+                     "to be pressed"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `pressed` property of \a element is true. See assert_touch_area_pressed().
+inline void assert_touch_area_released(const ElementHandle &element)
+{
+    bool pressed = false;
+    if (!cbindgen_private::slint_testing_touch_area_pressed(
+                &private_api::ElementHandleAccess::inner(element), &pressed)) {
+        std::cerr << "assert_touch_area_released FAILED: the element is not, and doesn't contain, "
+                     "a TouchArea"
+                  << std::endl;
+        std::abort();
+    }
+    if (pressed) {
+        std::cerr << "assert_touch_area_released FAILED: the touch area is pressed, expected it "
+                     "to be released"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `mouse-x` property of \a element doesn't match \a expected, within 0.01
+/// logical pixels.
+///
+/// The element is either a `TouchArea` or a widget that contains one. The position is relative to
+/// the touch area, and is the last one at which the mouse moved over it, or was dragged while
+/// pressed on it.
+inline void assert_pointer_x(const ElementHandle &element, float expected)
+{
+    float x = 0, y = 0;
+    if (!cbindgen_private::slint_testing_pointer_position(
+                &private_api::ElementHandleAccess::inner(element), &x, &y)) {
+        std::cerr << "assert_pointer_x FAILED: the element is not, and doesn't contain, a "
+                     "TouchArea"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::abs(x - expected) > 0.01f) {
+        std::cerr << "assert_pointer_x FAILED: the mouse-x of the touch area is " << x
+                  << ", expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `mouse-y` property of \a element doesn't match \a expected, within 0.01
+/// logical pixels. See assert_pointer_x().
+inline void assert_pointer_y(const ElementHandle &element, float expected)
+{
+    float x = 0, y = 0;
+    if (!cbindgen_private::slint_testing_pointer_position(
+                &private_api::ElementHandleAccess::inner(element), &x, &y)) {
+        std::cerr << "assert_pointer_y FAILED: the element is not, and doesn't contain, a "
+                     "TouchArea"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::abs(y - expected) > 0.01f) {
+        std::cerr << "assert_pointer_y FAILED: the mouse-y of the touch area is " << y
+                  << ", expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+inline KeyPressRecorder::KeyPressRecorder(const ElementHandle &element)
+{
+    using WeakRecorded = std::weak_ptr<std::vector<SharedString>>;
+    observer = cbindgen_private::slint_testing_observe_key_presses(
+            &private_api::ElementHandleAccess::inner(element),
+            [](void *user_data, const SharedString *text) {
+                if (auto recorded = reinterpret_cast<WeakRecorded *>(user_data)->lock()) {
+                    recorded->push_back(*text);
+                }
+            },
+            new WeakRecorded(recorded),
+            [](void *user_data) { delete reinterpret_cast<WeakRecorded *>(user_data); });
+    if (!observer) {
+        std::cerr << "record_key_presses FAILED: the element is not, and doesn't contain, a "
+                     "FocusScope"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Starts recording the keys that are pressed in \a element, which is either a `FocusScope` or a
+/// widget that contains one. Aborts if there's no `FocusScope`.
+///
+/// Destroying the recorder restores the `key-pressed` handler that was set when it was created,
+/// unless another handler was set since, which also stops the recording.
+inline KeyPressRecorder record_key_presses(const ElementHandle &element)
+{
+    return KeyPressRecorder(element);
+}
+
+/// Aborts if the key with the text \a key wasn't pressed exactly \a expected times since
+/// \a recorder was created or cleared.
+///
+/// The text of a special key such as slint::platform::key_codes::Return is the same as that of
+/// the character that it represents, so both are counted together.
+inline void assert_key_pressed_count(const KeyPressRecorder &recorder, const SharedString &key,
+                                     std::size_t expected)
+{
+    auto count = recorder.count(key);
+    if (count != expected) {
+        std::cerr << "assert_key_pressed_count FAILED: the key \"" << std::string_view(key)
+                  << "\" was pressed " << count << " times, expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+inline SignalRecorder::SignalRecorder(const ElementHandle &element, std::string_view name)
+{
+    using WeakEmitted = std::weak_ptr<std::size_t>;
+    cbindgen_private::Slice<uint8_t> name_view {
+        const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(name.data())),
+        name.size()
+    };
+    observer = cbindgen_private::slint_testing_observe_callback(
+            &private_api::ElementHandleAccess::inner(element), &name_view,
+            [](void *user_data) {
+                if (auto emitted = reinterpret_cast<WeakEmitted *>(user_data)->lock()) {
+                    ++*emitted;
+                }
+            },
+            new WeakEmitted(emitted),
+            [](void *user_data) { delete reinterpret_cast<WeakEmitted *>(user_data); });
+    if (!observer) {
+        std::cerr << "record_signal FAILED: the element doesn't have, and doesn't contain, a "
+                     "callback named "
+                  << name << std::endl;
+        std::abort();
+    }
+}
+
+/// Starts counting the emissions of the callback named \a name of \a element, such as `clicked`
+/// or `edited`. Aborts if there's no such callback.
+///
+/// The callback is one of the built-in callbacks of `TouchArea`, `FocusScope`, `Flickable`, or
+/// `TextInput`. If \a element doesn't have it, the first of its descendants that does is used, so
+/// that this function can be used with widgets such as `Button` or `LineEdit`. The handler of the
+/// callback is still invoked. Destroying the recorder restores the handler that was set when it
+/// was created, unless another handler was set since, which also stops the counting.
+inline SignalRecorder record_signal(const ElementHandle &element, std::string_view name)
+{
+    return SignalRecorder(element, name);
+}
+
+/// Aborts if the callback counted by \a recorder wasn't emitted exactly \a expected times since
+/// the recorder was created or cleared.
+inline void assert_signal_emitted(const SignalRecorder &recorder, std::size_t expected)
+{
+    auto count = recorder.count();
+    if (count != expected) {
+        std::cerr << "assert_signal_emitted FAILED: the callback was emitted " << count
+                  << " times, expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+/// Calls \a invoke, which invokes a callback of a component and returns its result, and aborts
+/// if that result isn't \a expected.
+///
+/// Invoking a callback that has no handler returns the default value of its return type, such as
+/// `0` or an empty string, so prefer an \a expected value that differs from that default.
+template<typename T, typename F>
+void assert_callback_return_value(const T &expected, F invoke)
+{
+    auto actual = invoke();
+    if (!(actual == expected)) {
+        std::cerr << "assert_callback_return_value FAILED: ";
+        if constexpr (requires(std::ostream &os) {
+                          os << actual;
+                          os << expected;
+                      }) {
+            std::cerr << "the callback returned " << actual << ", expected " << expected;
+        } else {
+            std::cerr << "the callback didn't return the expected value";
+        }
+        std::cerr << std::endl;
+        std::abort();
+    }
+}
+
+/// Renders the window of \a component if a redraw is pending, and aborts if \a element wasn't
+/// last painted at \a position with \a size, in logical window coordinates.
+///
+/// Only elements that draw something, such as `Rectangle`, `Image`, `Text` or `Path`, are
+/// painted.
+template<typename T>
+void assert_painting_rect(const ComponentHandle<T> &component, const ElementHandle &element,
+                          LogicalPosition position, LogicalSize size)
+{
+    cbindgen_private::LogicalRect rect;
+    if (!cbindgen_private::slint_testing_window_painting_rect(
+                &component->window().window_handle(),
+                &private_api::ElementHandleAccess::inner(element), &rect)) {
+        std::cerr << "assert_painting_rect FAILED: the element was not painted" << std::endl;
+        std::abort();
+    }
+    if (rect.x != position.x || rect.y != position.y || rect.width != size.width
+        || rect.height != size.height) {
+        std::cerr << "assert_painting_rect FAILED: the element was painted at " << rect.x << ", "
+                  << rect.y << " with size " << rect.width << "x" << rect.height
+                  << ", expected " << position.x << ", " << position.y << " with size "
+                  << size.width << "x" << size.height << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the children of \a element aren't clipped to the rectangle at \a position with
+/// \a size, in logical window coordinates.
+///
+/// The children are clipped by the element if it is a `Rectangle` with `clip: true`, or a
+/// `Flickable`, and by all the ancestors of the element that clip their children.
+inline void assert_clipping_rect(const ElementHandle &element, LogicalPosition position,
+                                 LogicalSize size)
+{
+    cbindgen_private::LogicalRect rect;
+    if (!cbindgen_private::slint_testing_clipping_rect(
+                &private_api::ElementHandleAccess::inner(element), &rect)) {
+        std::cerr << "assert_clipping_rect FAILED: the children of the element aren't clipped"
+                  << std::endl;
+        std::abort();
+    }
+    if (rect.x != position.x || rect.y != position.y || rect.width != size.width
+        || rect.height != size.height) {
+        std::cerr << "assert_clipping_rect FAILED: the children of the element are clipped at "
+                  << rect.x << ", " << rect.y << " with size " << rect.width << "x"
+                  << rect.height << ", expected " << position.x << ", " << position.y
+                  << " with size " << size.width << "x" << size.height << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `opacity` property of \a element doesn't match \a expected, within 0.001.
+///
+/// This is the opacity of the element itself, not multiplied with the opacity of its ancestors.
+inline void assert_opacity(const ElementHandle &element, float expected)
+{
+    float opacity = 0;
+    if (!cbindgen_private::slint_testing_element_opacity(
+                &private_api::ElementHandleAccess::inner(element), &opacity)) {
+        std::cerr << "assert_opacity FAILED: the element doesn't exist anymore" << std::endl;
+        std::abort();
+    }
+    if (std::abs(opacity - expected) > 0.001f) {
+        std::cerr << "assert_opacity FAILED: the opacity of the element is " << opacity
+                  << ", expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `rotation-angle` property of \a element doesn't match \a expected_degrees,
+/// within 0.01 degrees.
+inline void assert_rotation_angle(const ElementHandle &element, float expected_degrees)
+{
+    float angle = 0;
+    if (!cbindgen_private::slint_testing_element_rotation_angle(
+                &private_api::ElementHandleAccess::inner(element), &angle)) {
+        std::cerr << "assert_rotation_angle FAILED: the element doesn't exist anymore"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::abs(angle - expected_degrees) > 0.01f) {
+        std::cerr << "assert_rotation_angle FAILED: the element is rotated by " << angle
+                  << " degrees, expected " << expected_degrees << " degrees" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `drop-shadow-blur`, `drop-shadow-offset-x`, `drop-shadow-offset-y`, and
+/// `drop-shadow-color` properties of \a element don't match \a blur, \a offset_x, \a offset_y,
+/// and \a color. The lengths are in logical pixels and are compared within 0.01.
+///
+/// An element without drop shadow has no blur, no offset, and a transparent shadow color.
+inline void assert_drop_shadow(const ElementHandle &element, float blur, float offset_x,
+                               float offset_y, const Color &color)
+{
+    float actual_blur = 0, actual_offset_x = 0, actual_offset_y = 0;
+    uint32_t argb_color = 0;
+    if (!cbindgen_private::slint_testing_element_drop_shadow(
+                &private_api::ElementHandleAccess::inner(element), &actual_blur, &actual_offset_x,
+                &actual_offset_y, &argb_color)) {
+        std::cerr << "assert_drop_shadow FAILED: the element doesn't exist anymore" << std::endl;
+        std::abort();
+    }
+    auto actual_color = Color::from_argb_encoded(argb_color);
+    if (std::abs(actual_blur - blur) > 0.01f || std::abs(actual_offset_x - offset_x) > 0.01f
+        || std::abs(actual_offset_y - offset_y) > 0.01f || actual_color != color) {
+        std::cerr << "assert_drop_shadow FAILED: the drop shadow of the element has blur "
+                  << actual_blur << ", offset (" << actual_offset_x << ", " << actual_offset_y
+                  << ") and color " << actual_color << ", expected blur " << blur << ", offset ("
+                  << offset_x << ", " << offset_y << ") and color " << color << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if \a element isn't a `Rectangle` whose top-left, top-right, bottom-right, and
+/// bottom-left corners have the radii \a top_left, \a top_right, \a bottom_right, and
+/// \a bottom_left, in logical pixels, within 0.01.
+inline void assert_border_radii(const ElementHandle &element, float top_left, float top_right,
+                                float bottom_right, float bottom_left)
+{
+    float actual[4] = { 0, 0, 0, 0 };
+    if (!cbindgen_private::slint_testing_element_border_radius(
+                &private_api::ElementHandleAccess::inner(element), &actual[0], &actual[1],
+                &actual[2], &actual[3])) {
+        std::cerr << "assert_border_radii FAILED: the element isn't a Rectangle" << std::endl;
+        std::abort();
+    }
+    float expected[4] = { top_left, top_right, bottom_right, bottom_left };
+    for (int i = 0; i < 4; ++i) {
+        if (std::abs(actual[i] - expected[i]) > 0.01f) {
+            std::cerr << "assert_border_radii FAILED: the corners of the element have the radii "
+                      << actual[0] << ", " << actual[1] << ", " << actual[2] << ", " << actual[3]
+                      << ", expected " << expected[0] << ", " << expected[1] << ", "
+                      << expected[2] << ", " << expected[3]
+                      << " (top-left, top-right, bottom-right, bottom-left)" << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Aborts if \a element isn't a `Rectangle` whose four corners have the radius \a expected, in
+/// logical pixels, within 0.01.
+///
+/// The radius of a corner is the one of its `border-*-radius` property if set, and the one of
+/// `border-radius` otherwise.
+inline void assert_border_radius(const ElementHandle &element, float expected)
+{
+    assert_border_radii(element, expected, expected, expected, expected);
+}
+
+/// Aborts if \a element isn't a `Path` whose `stroke-width` property matches \a expected, in
+/// logical pixels, within 0.01.
+inline void assert_stroke_width(const ElementHandle &element, float expected)
+{
+    float width = 0;
+    if (!cbindgen_private::slint_testing_stroke_width(
+                &private_api::ElementHandleAccess::inner(element), &width)) {
+        std::cerr << "assert_stroke_width FAILED: the element isn't a Path" << std::endl;
+        std::abort();
+    }
+    if (std::abs(width - expected) > 0.01f) {
+        std::cerr << "assert_stroke_width FAILED: the stroke width of the path is " << width
+                  << "px, expected " << expected << "px" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the brush property named \a property of \a element, such as `background` or `fill`,
+/// isn't a linear gradient with the color stops \a expected. Each stop is a color and a position
+/// between 0 and 1, compared within 0.001. The stops are sorted by position.
+inline void assert_linear_gradient_stops(const ElementHandle &element, std::string_view property,
+                                         const std::vector<std::pair<Color, float>> &expected)
+{
+    cbindgen_private::Slice<uint8_t> property_view {
+        const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(property.data())),
+        property.size()
+    };
+    SharedVector<uint32_t> argb_colors;
+    SharedVector<float> positions;
+    if (!cbindgen_private::slint_testing_linear_gradient_stops(
+                &private_api::ElementHandleAccess::inner(element), &property_view, &argb_colors,
+                &positions)) {
+        std::cerr << "assert_linear_gradient_stops FAILED: the " << property
+                  << " of the element isn't a linear gradient" << std::endl;
+        std::abort();
+    }
+    bool matches = argb_colors.size() == expected.size();
+    for (std::size_t i = 0; matches && i < expected.size(); ++i) {
+        matches = Color::from_argb_encoded(argb_colors[i]) == expected[i].first
+                && std::abs(positions[i] - expected[i].second) <= 0.001f;
+    }
+    if (!matches) {
+        std::cerr << "assert_linear_gradient_stops FAILED: the " << property
+                  << " of the element has the gradient stops";
+        for (std::size_t i = 0; i < argb_colors.size(); ++i) {
+            std::cerr << " (" << Color::from_argb_encoded(argb_colors[i]) << ", " << positions[i]
+                      << ")";
+        }
+        std::cerr << ", expected";
+        for (const auto &[color, position] : expected) {
+            std::cerr << " (" << color << ", " << position << ")";
+        }
+        std::cerr << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the text of \a element isn't rendered with the font family \a expected_family. Use
+/// an empty string to check that the default font of the platform is used.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these. The family is
+/// the one of its `font-family` property if set, and the `default-font-family` of the window
+/// otherwise.
+inline void assert_font_family(const ElementHandle &element, std::string_view expected_family)
+{
+    SharedString family;
+    if (!cbindgen_private::slint_testing_font_family(
+                &private_api::ElementHandleAccess::inner(element), &family)) {
+        std::cerr << "assert_font_family FAILED: the element is not, and doesn't contain, a Text "
+                     "or a TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::string_view(family) != expected_family) {
+        std::cerr << "assert_font_family FAILED: the font family of the text is \""
+                  << std::string_view(family) << "\", expected \"" << expected_family << "\""
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the text of \a element isn't rendered with the font size \a expected_px, in logical
+/// pixels, within 0.01.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these. The size is
+/// the one of its `font-size` property if set, and the `default-font-size` of the window
+/// otherwise. The assertion fails if neither is set, since the size then depends on the platform.
+inline void assert_font_size(const ElementHandle &element, float expected_px)
+{
+    bool has_size = false;
+    float size = 0;
+    if (!cbindgen_private::slint_testing_font_size(
+                &private_api::ElementHandleAccess::inner(element), &has_size, &size)) {
+        std::cerr << "assert_font_size FAILED: the element is not, and doesn't contain, a Text or "
+                     "a TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (!has_size) {
+        std::cerr << "assert_font_size FAILED: the text is rendered with the default font size of "
+                     "the platform, expected "
+                  << expected_px << "px" << std::endl;
+        std::abort();
+    }
+    if (std::abs(size - expected_px) > 0.01f) {
+        std::cerr << "assert_font_size FAILED: the font size of the text is " << size
+                  << "px, expected " << expected_px << "px" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `horizontal-alignment` and `vertical-alignment` properties of the text of
+/// \a element don't match \a expected_horizontal and \a expected_vertical.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+inline void assert_text_alignment(const ElementHandle &element,
+                                  cbindgen_private::TextHorizontalAlignment expected_horizontal,
+                                  cbindgen_private::TextVerticalAlignment expected_vertical)
+{
+    cbindgen_private::TextHorizontalAlignment horizontal {};
+    cbindgen_private::TextVerticalAlignment vertical {};
+    if (!cbindgen_private::slint_testing_text_alignment(
+                &private_api::ElementHandleAccess::inner(element), &horizontal, &vertical)) {
+        std::cerr << "assert_text_alignment FAILED: the element is not, and doesn't contain, a "
+                     "Text or a TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (horizontal != expected_horizontal || vertical != expected_vertical) {
+        std::cerr << "assert_text_alignment FAILED: the text is aligned " << int(horizontal)
+                  << " and " << int(vertical) << ", expected " << int(expected_horizontal)
+                  << " and " << int(expected_vertical) << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `wrap` property of the text of \a element doesn't match \a expected.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+inline void assert_text_wrap(const ElementHandle &element, cbindgen_private::TextWrap expected)
+{
+    cbindgen_private::TextWrap wrap {};
+    if (!cbindgen_private::slint_testing_text_wrap(
+                &private_api::ElementHandleAccess::inner(element), &wrap)) {
+        std::cerr << "assert_text_wrap FAILED: the element is not, and doesn't contain, a Text or "
+                     "a TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (wrap != expected) {
+        std::cerr << "assert_text_wrap FAILED: the text wraps with " << int(wrap) << ", expected "
+                  << int(expected) << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `letter-spacing` property of the text of \a element doesn't match
+/// \a expected_px, in logical pixels, within 0.01.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+inline void assert_letter_spacing(const ElementHandle &element, float expected_px)
+{
+    float spacing = 0;
+    if (!cbindgen_private::slint_testing_letter_spacing(
+                &private_api::ElementHandleAccess::inner(element), &spacing)) {
+        std::cerr << "assert_letter_spacing FAILED: the element is not, and doesn't contain, a "
+                     "Text or a TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::abs(spacing - expected_px) > 0.01f) {
+        std::cerr << "assert_letter_spacing FAILED: the letter spacing of the text is " << spacing
+                  << "px, expected " << expected_px << "px" << std::endl;
+        std::abort();
+    }
+}
+
+/// Renders the window of \a component if a redraw is pending, and aborts if the region that this
+/// frame repainted doesn't have the bounds \a position and \a size, in logical window
+/// coordinates.
+///
+/// Only the parts of the window whose appearance changed since the previous frame are repainted.
+/// The region is empty when no redraw was pending.
+template<typename T>
+void assert_repaint_region(const ComponentHandle<T> &component, LogicalPosition position,
+                           LogicalSize size)
+{
+    cbindgen_private::LogicalRect rect;
+    if (!cbindgen_private::slint_testing_window_repaint_region(
+                &component->window().window_handle(), &rect)) {
+        std::cerr << "assert_repaint_region FAILED: the window wasn't created by the testing "
+                     "backend"
+                  << std::endl;
+        std::abort();
+    }
+    if (rect.x != position.x || rect.y != position.y || rect.width != size.width
+        || rect.height != size.height) {
+        std::cerr << "assert_repaint_region FAILED: the repainted region is at " << rect.x << ", "
+                  << rect.y << " with size " << rect.width << "x" << rect.height
+                  << ", expected " << position.x << ", " << position.y << " with size "
+                  << size.width << "x" << size.height << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the items of the `ComboBox` widget \a combobox aren't \a expected, in that order.
+///
+/// The items are read from the popup of the combo box, which is opened by simulating a click on
+/// the combo box, and closed again afterwards. The current value of the combo box doesn't change.
+template<typename T>
+void assert_combobox_items(const ComponentHandle<T> &component, const ElementHandle &combobox,
+                           std::vector<std::string_view> expected)
+{
+    SharedVector<SharedString> items;
+    if (!cbindgen_private::slint_testing_combobox_items(
+                &component->window().window_handle(),
+                &private_api::ElementHandleAccess::inner(combobox), &items)) {
+        std::cerr << "assert_combobox_items FAILED: clicking on the element didn't open the popup "
+                     "of a combo box"
+                  << std::endl;
+        std::abort();
+    }
+    bool equal = items.size() == expected.size();
+    for (std::size_t i = 0; equal && i < items.size(); ++i) {
+        equal = std::string_view(items[i]) == expected[i];
+    }
+    if (!equal) {
+        std::cerr << "assert_combobox_items FAILED: the items of the combo box are [";
+        for (std::size_t i = 0; i < items.size(); ++i) {
+            std::cerr << (i ? ", " : "") << std::string_view(items[i]);
+        }
+        std::cerr << "]" << std::endl;
+        std::abort();
+    }
+}
+
+
+/// Aborts if the range of the `Slider` widget \a slider isn't from \a expected_minimum to
+/// \a expected_maximum.
+///
+/// The range is read from the `accessible-value-minimum` and `accessible-value-maximum`
+/// properties, which the sliders of all the styles forward from their `minimum` and `maximum`.
+inline void assert_slider_range(const ElementHandle &slider, float expected_minimum,
+                                float expected_maximum)
+{
+    auto minimum = slider.accessible_value_minimum();
+    auto maximum = slider.accessible_value_maximum();
+    if (!minimum || !maximum) {
+        std::cerr << "assert_slider_range FAILED: the element is not a slider" << std::endl;
+        std::abort();
+    }
+    if (*minimum != expected_minimum || *maximum != expected_maximum) {
+        std::cerr << "assert_slider_range FAILED: the range of the slider is " << *minimum << ".."
+                  << *maximum << ", expected " << expected_minimum << ".." << expected_maximum
+                  << std::endl;
+        std::abort();
+    }
+}
+
+}
+
+namespace slint::private_api {
+// Aborts if the element doesn't have the accessible role, or if its accessible-checked property
+// isn't expected. The function and widget names are used for the error messages.
+inline void assert_checked_state(const testing::ElementHandle &element,
+                                 cbindgen_private::AccessibleRole role, std::string_view function,
+                                 std::string_view widget, bool expected)
+{
+    if (element.accessible_role() != role) {
+        std::cerr << function << " FAILED: the element is not a " << widget << std::endl;
+        std::abort();
+    }
+    bool checked = element.accessible_checked().value_or(false);
+    if (checked != expected) {
+        auto state = [](bool checked) { return checked ? "checked" : "unchecked"; };
+        std::cerr << function << " FAILED: the " << widget << " is " << state(checked)
+                  << ", expected it " << state(expected) << std::endl;
+        std::abort();
+    }
+}
+}
+
+namespace slint::testing {
+
+/// Aborts if the `CheckBox` widget \a checkbox isn't checked when \a expected is true, or if it
+/// is checked when \a expected is false.
+///
+/// The state is read from the `accessible-checked` property, which the check boxes of all the
+/// styles forward from their `checked` property.
+///
+/// This doesn't support tri-state check boxes: Slint's `CheckBox` has no indeterminate state, and
+/// no `check-state` property to read it from, so only the checked and unchecked states can be
+/// tested.
+inline void assert_checkbox_checked(const ElementHandle &checkbox, bool expected)
+{
+    private_api::assert_checked_state(checkbox, cbindgen_private::AccessibleRole::Checkbox,
+                                      "assert_checkbox_checked", "check box", expected);
+}
+
+/// Aborts if the `progress` of the `ProgressIndicator` widget \a indicator doesn't match
+/// \a expected, within 0.0001.
+///
+/// The progress is read from the `accessible-value` property, which the progress indicators of
+/// all the styles forward from their `progress`.
+inline void assert_progress_value(const ElementHandle &indicator, float expected)
+{
+    if (indicator.accessible_role() != cbindgen_private::AccessibleRole::ProgressIndicator) {
+        std::cerr << "assert_progress_value FAILED: the element is not a progress indicator"
+                  << std::endl;
+        std::abort();
+    }
+    auto value = indicator.accessible_value();
+    auto progress = value ? private_api::string_to_float(*value) : std::nullopt;
+    if (!progress || std::abs(*progress - expected) > 0.0001f) {
+        std::cerr << "assert_progress_value FAILED: the progress is "
+                  << (value ? std::string_view(*value) : "unknown") << ", expected " << expected
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `value` of the `SpinBox` widget \a spinbox isn't \a expected.
+///
+/// The value is read from the `accessible-value` property, which the spin boxes of all the styles
+/// forward from their `value`.
+inline void assert_spin_box_value(const ElementHandle &spinbox, int expected)
+{
+    if (spinbox.accessible_role() != cbindgen_private::AccessibleRole::Spinbox) {
+        std::cerr << "assert_spin_box_value FAILED: the element is not a spin box" << std::endl;
+        std::abort();
+    }
+    auto value = spinbox.accessible_value();
+    auto number = value ? private_api::string_to_float(*value) : std::nullopt;
+    if (number != float(expected)) {
+        std::cerr << "assert_spin_box_value FAILED: the value of the spin box is "
+                  << (value ? std::string_view(*value) : "unknown") << ", expected " << expected
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the current tab of the `TabWidget` widget \a tab_widget isn't the one at
+/// \a expected_index.
+///
+/// The current tab is the one whose content the tab widget shows, which follows its
+/// `current-index` property. Moving the keyboard focus between the tabs doesn't change it.
+inline void assert_tab_selected(const ElementHandle &tab_widget, int expected_index)
+{
+    int index = 0;
+    if (!cbindgen_private::slint_testing_tab_widget_current_index(
+                &private_api::ElementHandleAccess::inner(tab_widget), &index)) {
+        std::cerr << "assert_tab_selected FAILED: the element is not a tab widget" << std::endl;
+        std::abort();
+    }
+    if (index != expected_index) {
+        std::cerr << "assert_tab_selected FAILED: the current tab is the one at index " << index
+                  << ", expected " << expected_index << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `Switch` widget \a switch_ isn't checked.
+///
+/// The state is read from the `accessible-checked` property, which the switches of all the styles
+/// forward from their `checked` property.
+inline void assert_switch_checked(const ElementHandle &switch_)
+{
+    private_api::assert_checked_state(switch_, cbindgen_private::AccessibleRole::Switch,
+                                      "assert_switch_checked", "switch", true);
+}
+
+/// Aborts if the `Switch` widget \a switch_ is checked. See assert_switch_checked().
+inline void assert_switch_unchecked(const ElementHandle &switch_)
+{
+    private_api::assert_checked_state(switch_, cbindgen_private::AccessibleRole::Switch,
+                                      "assert_switch_unchecked", "switch", false);
+}
+
+/// Aborts if the text that is selected in \a element doesn't start at the byte offset
+/// \a expected_start and end at \a expected_end.
+///
+/// The element is either a `TextInput` or a widget that contains one, such as `LineEdit` or
+/// `TextEdit`. The start is the smaller of the cursor and anchor positions, regardless of the
+/// direction in which the text was selected. Without selection, both offsets are the position of
+/// the cursor.
+inline void assert_text_selection(const ElementHandle &element, std::size_t expected_start,
+                                  std::size_t expected_end)
+{
+    std::size_t start = 0, end = 0;
+    if (!cbindgen_private::slint_testing_text_selection(
+                &private_api::ElementHandleAccess::inner(element), &start, &end)) {
+        std::cerr << "assert_text_selection FAILED: the element is not, and doesn't contain, a "
+                     "TextInput"
+                  << std::endl;
+        std::abort();
+    }
+    if (start != expected_start || end != expected_end) {
+        std::cerr << "assert_text_selection FAILED: the selection is from byte " << start << " to "
+                  << end << ", expected from " << expected_start << " to " << expected_end
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `viewport-x` and `viewport-y` properties of the `ListView` widget \a list_view
+/// don't match \a expected_x and \a expected_y, within 0.01 logical pixels.
+///
+/// The element can also be a `ScrollView` or a `Flickable`. The viewport moves up and to the left
+/// when scrolling, so the coordinates are zero or negative.
+inline void assert_list_view_scroll_position(const ElementHandle &list_view, float expected_x,
+                                             float expected_y)
+{
+    float x = 0, y = 0;
+    if (!cbindgen_private::slint_testing_scroll_position(
+                &private_api::ElementHandleAccess::inner(list_view), &x, &y)) {
+        std::cerr << "assert_list_view_scroll_position FAILED: the element is not, and doesn't "
+                     "contain, a Flickable"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::abs(x - expected_x) > 0.01f || std::abs(y - expected_y) > 0.01f) {
+        std::cerr << "assert_list_view_scroll_position FAILED: the scroll position is (" << x
+                  << ", " << y << "), expected (" << expected_x << ", " << expected_y << ")"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `source.width` and `source.height` of the `Image` element \a image aren't
+/// \a expected_width and \a expected_height.
+///
+/// This is the size of the image in pixels, regardless of the `width` and `height` with which the
+/// element displays it. It is zero when the image failed to load.
+inline void assert_image_size(const ElementHandle &image, float expected_width,
+                              float expected_height)
+{
+    float width = 0, height = 0;
+    if (!cbindgen_private::slint_testing_image_size(
+                &private_api::ElementHandleAccess::inner(image), &width, &height)) {
+        std::cerr << "assert_image_size FAILED: the element is not an Image" << std::endl;
+        std::abort();
+    }
+    if (width != expected_width || height != expected_height) {
+        std::cerr << "assert_image_size FAILED: the size of the image is " << width << "x"
+                  << height << ", expected " << expected_width << "x" << expected_height
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `image-fit` property of the `Image` element \a image isn't \a expected.
+///
+/// The fit mode determines how the source is scaled when the element doesn't have the size of
+/// the image. It is `fill` by default, or `contain` for an `Image` in a layout that has no
+/// explicit `width` or `height`.
+inline void assert_image_scaling(const ElementHandle &image, cbindgen_private::ImageFit expected)
+{
+    cbindgen_private::ImageFit fit {};
+    if (!cbindgen_private::slint_testing_image_fit(
+                &private_api::ElementHandleAccess::inner(image), &fit)) {
+        std::cerr << "assert_image_scaling FAILED: the element is not an Image" << std::endl;
+        std::abort();
+    }
+    if (fit != expected) {
+        std::cerr << "assert_image_scaling FAILED: the image is scaled with " << int(fit)
+                  << ", expected " << int(expected) << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the tree of elements that starts at \a element doesn't have \a expected levels.
+///
+/// An element without children has one level. The elements that were inlined into their parent,
+/// such as the root of a sub-component, don't add a level, as in dump_item_tree(). Use it to
+/// verify the nesting of elements that are created from a model, such as the rows of a tree that
+/// indent their children with nested repeaters.
+inline void assert_item_tree_depth(const ElementHandle &element, uint32_t expected)
+{
+    uint32_t depth = 0;
+    if (!cbindgen_private::slint_testing_item_tree_depth(
+                &private_api::ElementHandleAccess::inner(element), &depth)) {
+        std::cerr << "assert_item_tree_depth FAILED: the element doesn't exist anymore"
+                  << std::endl;
+        std::abort();
+    }
+    if (depth != expected) {
+        std::cerr << "assert_item_tree_depth FAILED: the tree of elements has " << depth
+                  << " level(s), expected " << expected << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the `text` of the `LineEdit` widget \a search_field isn't \a expected.
+///
+/// The text is read from the `accessible-value` property, which the line edits of all the styles
+/// forward from their `text`.
+inline void assert_search_text(const ElementHandle &search_field, std::string_view expected)
+{
+    if (search_field.accessible_role() != cbindgen_private::AccessibleRole::TextInput) {
+        std::cerr << "assert_search_text FAILED: the element is not a line edit" << std::endl;
+        std::abort();
+    }
+    auto text = search_field.accessible_value();
+    if (!text || std::string_view(*text) != expected) {
+        std::cerr << "assert_search_text FAILED: the text of the search field is "
+                  << (text ? std::string_view(*text) : "unknown") << ", expected " << expected
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the size of the window of \a component isn't \a expected_width x \a expected_height
+/// logical pixels.
+///
+/// A window gets its preferred size when it is created, for example from the `preferred-width`
+/// and `preferred-height` properties of the `Window` element, and a new size when it is resized.
+template<typename T>
+void assert_window_size(const ComponentHandle<T> &component, float expected_width,
+                        float expected_height)
+{
+    float width = 0, height = 0;
+    cbindgen_private::slint_testing_window_size(&component->window().window_handle(), &width,
+                                                &height);
+    if (width != expected_width || height != expected_height) {
+        std::cerr << "assert_window_size FAILED: the window size is " << width << "x" << height
+                  << ", expected " << expected_width << "x" << expected_height << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the position of the window of \a component on the screen isn't \a expected_x,
+/// \a expected_y logical pixels.
+///
+/// The testing backend places new windows at the origin of the screen, and moves them when the
+/// application calls Window::set_position().
+template<typename T>
+void assert_window_position(const ComponentHandle<T> &component, float expected_x,
+                            float expected_y)
+{
+    const auto &window = component->window();
+    auto position = window.position();
+    float x = position.x / window.scale_factor(), y = position.y / window.scale_factor();
+    if (x != expected_x || y != expected_y) {
+        std::cerr << "assert_window_position FAILED: the window position is (" << x << ", " << y
+                  << "), expected (" << expected_x << ", " << expected_y << ")" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the window of \a component isn't visible.
+///
+/// A window becomes visible when show() is called, and hidden again with hide().
+template<typename T>
+void assert_window_visible(const ComponentHandle<T> &component)
+{
+    if (!component->window().is_visible()) {
+        std::cerr << "assert_window_visible FAILED: the window is hidden" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the window of \a component is visible.
+///
+/// See assert_window_visible() for when a window is visible.
+template<typename T>
+void assert_window_hidden(const ComponentHandle<T> &component)
+{
+    if (component->window().is_visible()) {
+        std::cerr << "assert_window_hidden FAILED: the window is visible" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the keyboard focus in the window of \a component isn't on the element with the id
+/// \a expected_id.
+///
+/// The id must be qualified with the name of the component, as in
+/// ElementHandle::find_by_element_id(). An element also counts as focused when the focus is on
+/// one of its children, so that this function can be used with widgets such as `LineEdit`.
+template<typename T>
+void assert_focus_element(const ComponentHandle<T> &component, std::string_view expected_id)
+{
+    cbindgen_private::Slice<uint8_t> expected_id_view {
+        const_cast<unsigned char *>(reinterpret_cast<const unsigned char *>(expected_id.data())),
+        expected_id.size()
+    };
+    if (!cbindgen_private::slint_testing_window_focus_has_element_id(
+                &component->window().window_handle(), &expected_id_view)) {
+        std::cerr << "assert_focus_element FAILED: expected the focus on " << expected_id
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Records the properties that a function reads, to verify later whether any of them changed.
+///
+/// The properties are tracked the same way as in the bindings of a `.slint` file: reading a
+/// property whose value is computed by a binding also tracks the properties that this binding
+/// reads.
+class PropertyWatcher
+{
+public:
+    /// Calls \a read and records the properties that it reads.
+    explicit PropertyWatcher(const std::function<void()> &read)
+    {
+        tracker.evaluate_as_dependency_root(read);
+    }
+    PropertyWatcher(const PropertyWatcher &) = delete;
+    PropertyWatcher &operator=(const PropertyWatcher &) = delete;
+
+    /// Returns true if any of the recorded properties changed since they were read.
+    bool is_dirty() const { return tracker.is_dirty(); }
+
+private:
+    private_api::PropertyTracker tracker;
+};
+
+/// Aborts if none of the properties recorded by \a watcher changed since they were read.
+inline void assert_property_dirty(const PropertyWatcher &watcher)
+{
+    if (!watcher.is_dirty()) {
+        std::cerr << "assert_property_dirty FAILED: none of the watched properties changed"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if any of the properties recorded by \a watcher changed since they were read.
+///
+/// Setting a property to the value that it already has doesn't change it.
+inline void assert_property_clean(const PropertyWatcher &watcher)
+{
+    if (watcher.is_dirty()) {
+        std::cerr << "assert_property_clean FAILED: the watched properties changed" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if two consecutive rows of \a model are out of order, that is if \a in_order returns
+/// false when it is called with the data of a row and the data of the row that follows it.
+///
+/// \a in_order returns true if its first argument may come before its second one. Use it to
+/// verify the rows of a SortModel, or of any model that is expected to be sorted.
+template<typename ModelData>
+void assert_model_sorted(
+        const std::shared_ptr<Model<ModelData>> &model,
+        std::type_identity_t<std::function<bool(const ModelData &, const ModelData &)>> in_order)
+{
+    auto rows = private_api::model_rows(model);
+    for (std::size_t row = 1; row < rows.size(); ++row) {
+        if (!in_order(rows[row - 1], rows[row])) {
+            std::cerr << "assert_model_sorted FAILED: the rows " << (row - 1) << " and " << row
+                      << " of the model are out of order" << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Aborts if a row of \a model doesn't match \a predicate, that is if \a predicate returns false
+/// when it is called with the data of that row.
+///
+/// Use it to verify that a FilterModel, or any model that is expected to be filtered, only
+/// contains the rows that it should.
+template<typename ModelData>
+void assert_model_filtered(const std::shared_ptr<Model<ModelData>> &model,
+                           std::type_identity_t<std::function<bool(const ModelData &)>> predicate)
+{
+    auto rows = private_api::model_rows(model);
+    for (std::size_t row = 0; row < rows.size(); ++row) {
+        if (!predicate(rows[row])) {
+            std::cerr << "assert_model_filtered FAILED: the row " << row
+                      << " of the model doesn't match the predicate" << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Aborts if \a model doesn't contain the rows of \a original in the reverse order, that is if
+/// they don't have the same number of rows, or if any row `i` of \a model isn't equal to the row
+/// `N - 1 - i` of \a original, where `N` is that number of rows.
+///
+/// Use it to verify a ReverseModel against its source model.
+template<typename ModelData>
+void assert_model_reversed(const std::shared_ptr<Model<ModelData>> &model,
+                           const std::shared_ptr<Model<ModelData>> &original)
+{
+    auto rows = private_api::model_rows(model);
+    auto original_rows = private_api::model_rows(original);
+    if (rows.size() != original_rows.size()) {
+        std::cerr << "assert_model_reversed FAILED: the model has " << rows.size()
+                  << " rows, expected the " << original_rows.size()
+                  << " rows of the original model" << std::endl;
+        std::abort();
+    }
+    for (std::size_t row = 0; row < rows.size(); ++row) {
+        if (!(rows[row] == original_rows[rows.size() - 1 - row])) {
+            std::cerr << "assert_model_reversed FAILED: the row " << row
+                      << " of the model isn't equal to the row " << (rows.size() - 1 - row)
+                      << " of the original model" << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Aborts if \a model doesn't have \a expected_total_rows rows, or if it doesn't provide the data
+/// of each of them.
+///
+/// Use it to verify a model that concatenates other models, and whose number of rows is the sum
+/// of theirs. A row that maps to the wrong model, or past the end of one, typically returns no
+/// data.
+template<typename ModelData>
+void assert_model_concatenated(const std::shared_ptr<Model<ModelData>> &model,
+                               std::size_t expected_total_rows)
+{
+    auto row_count = model->row_count();
+    if (row_count != expected_total_rows) {
+        std::cerr << "assert_model_concatenated FAILED: the model has " << row_count
+                  << " rows, expected " << expected_total_rows << std::endl;
+        std::abort();
+    }
+    for (std::size_t row = 0; row < row_count; ++row) {
+        if (!model->row_data(row)) {
+            std::cerr << "assert_model_concatenated FAILED: the row " << row
+                      << " of the model has no data" << std::endl;
+            std::abort();
+        }
+    }
+}
+
+/// Aborts if the data of the row \a row of \a model isn't \a expected, or if the model has no
+/// such row.
+///
+/// Use it to verify the result of the function of a MapModel, or of any model that transforms the
+/// rows of another one.
+template<typename ModelData>
+void assert_model_mapped(const std::shared_ptr<Model<ModelData>> &model, std::size_t row,
+                         const std::type_identity_t<ModelData> &expected)
+{
+    auto row_count = model->row_count();
+    if (row >= row_count) {
+        std::cerr << "assert_model_mapped FAILED: the model has no row " << row << ", it has "
+                  << row_count << " rows" << std::endl;
+        std::abort();
+    }
+    auto data = model->row_data(row);
+    if (!data || !(*data == expected)) {
+        std::cerr << "assert_model_mapped FAILED: the row " << row
+                  << " of the model isn't the expected value" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if \a timer isn't running.
+///
+/// A timer runs from the moment it is started until it is stopped. A single-shot timer also stops
+/// after its callback was invoked.
+inline void assert_timer_active(const Timer &timer)
+{
+    if (!timer.running()) {
+        std::cerr << "assert_timer_active FAILED: the timer isn't running" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if \a timer is running.
+///
+/// See assert_timer_active() for when a timer runs.
+inline void assert_timer_inactive(const Timer &timer)
+{
+    if (timer.running()) {
+        std::cerr << "assert_timer_inactive FAILED: the timer is running" << std::endl;
+        std::abort();
+    }
+}
+
+/// Calls \a set to change some properties, then \a read to read the properties that depend on
+/// them, and returns the time that elapsed.
+///
+/// Bindings are evaluated lazily, when the properties that they compute are read, so the measured
+/// time covers the change and the new evaluation of the bindings that \a read reaches. \a read is
+/// also called once before the measurement, so that the first evaluation of these bindings isn't
+/// measured.
+template<typename Set, typename Read>
+std::chrono::duration<double> measure_property_update_time(Set set, Read read)
+{
+    read();
+    auto start = std::chrono::steady_clock::now();
+    set();
+    read();
+    return std::chrono::steady_clock::now() - start;
+}
+
+#if defined(SLINT_FEATURE_TESTING_DEBUG_COUNTERS) || defined(DOXYGEN)
+/// Calls \a set to change some properties, then \a read to read the properties that depend on
+/// them, and returns how many bindings had to be evaluated again because of that change.
+///
+/// Bindings are evaluated lazily, when the properties that they compute are read, so the count
+/// covers the bindings that \a read reaches, including the ones of the properties that these
+/// bindings read in turn. \a read is also called once before, so that the first evaluation of
+/// these bindings isn't counted.
+///
+/// This requires the `SLINT_FEATURE_TESTING_DEBUG_COUNTERS` option.
+template<typename Set, typename Read>
+uint32_t count_property_evaluations(Set set, Read read)
+{
+    read();
+    auto start = cbindgen_private::slint_testing_binding_evaluation_count();
+    set();
+    read();
+    return cbindgen_private::slint_testing_binding_evaluation_count() - start;
+}
+#endif
+
+}
+
+namespace slint::private_api {
+// Returns true if a transition between the states of element is running, or an empty optional if
+// the element has no states or no debug information was generated
+inline std::optional<bool> is_in_transition(const testing::ElementHandle &element)
+{
+    bool has_states = false;
+    auto read = [&] { has_states = element.current_state().has_value(); };
+    bool running = cbindgen_private::slint_testing_is_animation_running(
+            [](void *user_data) { (*reinterpret_cast<decltype(read) *>(user_data))(); }, &read);
+    if (!has_states) {
+        return std::nullopt;
+    }
+    return running;
+}
+}
+
+namespace slint::testing {
+
+/// Aborts if no transition between the states of \a element is running, or if the element has no
+/// states.
+///
+/// A transition runs while one of the properties that it animates hasn't reached the value of the
+/// new state yet. Only the transitions of \a element are taken into account, not the other
+/// animations of the window. Advance the time with `mock_elapsed_time` to progress the transition.
+///
+/// The states require the debug information to be generated by the Slint compiler, see
+/// ElementHandle::find_by_element_id().
+inline void assert_in_transition(const ElementHandle &element)
+{
+    auto running = private_api::is_in_transition(element);
+    if (!running) {
+        std::cerr << "assert_in_transition FAILED: the element has no states, or no debug "
+                     "information was generated"
+                  << std::endl;
+        std::abort();
+    }
+    if (!*running) {
+        std::cerr << "assert_in_transition FAILED: no transition of the element is running"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if a transition between the states of \a element is running, or if the element has no
+/// states.
+///
+/// See assert_in_transition() for which animations count as transitions.
+inline void assert_not_in_transition(const ElementHandle &element)
+{
+    auto running = private_api::is_in_transition(element);
+    if (!running) {
+        std::cerr << "assert_not_in_transition FAILED: the element has no states, or no debug "
+                     "information was generated"
+                  << std::endl;
+        std::abort();
+    }
+    if (*running) {
+        std::cerr << "assert_not_in_transition FAILED: a transition of the element is running"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the state of \a element that currently applies isn't the one named
+/// \a expected_state, as declared in the `states` block of the element. Use an empty string to
+/// check that none of the states applies.
+inline void assert_state(const ElementHandle &element, std::string_view expected_state)
+{
+    auto state = element.current_state();
+    if (!state) {
+        std::cerr << "assert_state FAILED: the element has no states, or no debug information was "
+                     "generated"
+                  << std::endl;
+        std::abort();
+    }
+    if (std::string_view(*state) != expected_state) {
+        std::cerr << "assert_state FAILED: the current state of the element is \"" << *state
+                  << "\", expected \"" << expected_state << "\"" << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the animation of the property that \a read reads has finished, or if the property
+/// isn't animated. Only the animations that \a read evaluates are taken into account.
+template<typename Read>
+void assert_animation_running(Read read)
+{
+    if (!cbindgen_private::slint_testing_is_animation_running(
+                [](void *user_data) { (*reinterpret_cast<Read *>(user_data))(); }, &read)) {
+        std::cerr << "assert_animation_running FAILED: the animation of the property has finished"
+                  << std::endl;
+        std::abort();
+    }
+}
+
+/// Aborts if the animation of the property that \a read reads is still running.
+template<typename Read>
+void assert_animation_finished(Read read)
+{
+    if (cbindgen_private::slint_testing_is_animation_running(
+                [](void *user_data) { (*reinterpret_cast<Read *>(user_data))(); }, &read)) {
+        std::cerr << "assert_animation_finished FAILED: the animation of the property is still "
+                     "running"
+                  << std::endl;
+        std::abort();
+    }
+}
 }
 
 #endif // SLINT_FEATURE_TESTING
@@ -508,6 +508,64 @@ SCENARIO("Send key events")
     REQUIRE(*instance->get_property("result")->to_string() == "Hello keys!");
 }
 
+SCENARIO("Element property types")
+{
+    using namespace slint::interpreter;
+    using namespace slint;
+
+    ComponentCompiler compiler;
+    auto comp_def = compiler.build_from_source(R"(
+        component Card {
+            in property <[string]> lines;
+            label := Text { text: lines[0]; }
+        }
+        export component Dummy {
+            in-out property <int> count;
+            card := Card { }
+        }
+    )",
+                                               "");
+    REQUIRE(comp_def.has_value());
+    auto instance = comp_def->create();
+    slint::private_api::testing::assert_property_type(&*instance, "Dummy::root", "count",
+                                                      Value::Type::Number);
+    slint::private_api::testing::assert_property_type(&*instance, "Dummy::card", "lines",
+                                                      Value::Type::Model);
+    slint::private_api::testing::assert_property_type(&*instance, "Card::label", "text",
+                                                      Value::Type::String);
+
+    // A wrong type is reported rather than converted
+    auto type = slint::private_api::testing::element_property_type(&*instance, "Dummy::root",
+                                                                   "count");
+    REQUIRE(type.has_value());
+    REQUIRE(*type != Value::Type::String);
+    // Unknown elements and properties have no type
+    REQUIRE(!slint::private_api::testing::element_property_type(&*instance, "Dummy::root",
+                                                                "missing")
+                     .has_value());
+    REQUIRE(!slint::private_api::testing::element_property_type(&*instance, "Dummy::nothing",
+                                                                "count")
+                     .has_value());
+}
+
+SCENARIO("Assert no compile errors")
+{
+    slint::private_api::testing::assert_no_compile_errors(
+            "export component Dummy { in property <int> count; Text { text: count; } }");
+    // A component that is implicitly exported only produces a warning
+    slint::private_api::testing::assert_no_compile_errors("component Dummy { }");
+    REQUIRE(slint::private_api::testing::compile_errors("component Dummy { }").empty());
+
+    // Invalid sources produce diagnostics
+    auto errors = slint::private_api::testing::compile_errors(
+            "export component Dummy { Text { text: 42 + ; } }");
+    REQUIRE(!errors.empty());
+    REQUIRE(errors[0].line == 1);
+    errors = slint::private_api::testing::compile_errors(
+            "export component Dummy { in property <int> count: \"text\"; }");
+    REQUIRE(errors.size() == 1);
+}
+
 SCENARIO("Global properties")
 {
     using namespace slint::interpreter;
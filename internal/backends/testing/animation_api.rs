@@ -0,0 +1,161 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::ElementHandle;
+use i_slint_core::animations::{Instant, CURRENT_ANIMATION_DRIVER};
+
+/// Advances the time of the animations by `time_ms` milliseconds, and panics if the value that
+/// `value` reads from an animated property then doesn't match `expected`, within 0.01.
+///
+/// Slint animations don't run in real time in tests, so the value of a property that is being
+/// animated only changes when the mocked time advances. Read the property once before changing
+/// the properties it depends on, so that the animation starts at the time of the change.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <bool> moved;
+///         out property <length> rect-x: rect.x;
+///         rect := Rectangle {
+///             x: moved ? 100px : 0px;
+///             animate x { duration: 1000ms; }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// assert_eq!(app.get_rect_x(), 0.);
+/// app.set_moved(true);
+/// i_slint_backend_testing::assert_animation_value(25., 250, || app.get_rect_x());
+/// i_slint_backend_testing::assert_animation_value(100., 750, || app.get_rect_x());
+/// ```
+#[track_caller]
+pub fn assert_animation_value(expected: f32, time_ms: u64, value: impl FnOnce() -> f32) {
+    i_slint_core::tests::slint_mock_elapsed_time(time_ms);
+    let actual = value();
+    if (actual - expected).abs() > 0.01 {
+        panic!("After {time_ms}ms, the animated value is {actual}, expected {expected}");
+    }
+}
+
+/// Returns true if a transition between the states of `element` is running, or None if the
+/// element has no states or no debug information was generated.
+///
+/// With debug information, the binding of the current state name of an element also reads the
+/// properties that its transitions animate, so evaluating it evaluates their animations.
+pub(crate) fn is_in_transition(element: &ElementHandle) -> Option<bool> {
+    let mut has_states = false;
+    let running = is_animation_running(|| has_states = element.current_state().is_some());
+    has_states.then_some(running)
+}
+
+/// Panics if no transition between the states of `element` is running, or if the element has no
+/// states.
+///
+/// A transition runs while one of the properties that it animates hasn't reached the value of the
+/// new state yet. Only the transitions of `element` are taken into account, not the other
+/// animations of the window. Advance the time with `mock_elapsed_time` to progress the transition.
+///
+/// The states require the debug information to be generated by the Slint compiler, see
+/// [`ElementHandle::find_by_element_id()`].
+#[track_caller]
+pub fn assert_in_transition(element: &ElementHandle) {
+    match is_in_transition(element) {
+        None => panic!("The element has no states, or no debug information was generated"),
+        Some(false) => panic!("No transition of the element is running"),
+        Some(true) => (),
+    }
+}
+
+/// Panics if a transition between the states of `element` is running, or if the element has no
+/// states.
+///
+/// See [`assert_in_transition()`] for which animations count as transitions.
+#[track_caller]
+pub fn assert_not_in_transition(element: &ElementHandle) {
+    match is_in_transition(element) {
+        None => panic!("The element has no states, or no debug information was generated"),
+        Some(true) => panic!("A transition of the element is running"),
+        Some(false) => (),
+    }
+}
+
+/// Returns true if the animation of a property that `read` reads hasn't finished at the current
+/// time of the animations.
+pub(crate) fn is_animation_running(read: impl FnOnce()) -> bool {
+    CURRENT_ANIMATION_DRIVER.with(|driver| {
+        let had_active_animations = driver.has_active_animations();
+        // Moving the time forth and back marks the running animations dirty and resets the flag,
+        // so that `read` evaluates them again at the current time and sets the flag if they
+        // haven't finished.
+        let tick = driver.current_tick();
+        driver.update_animations(Instant(tick.0 + 1));
+        driver.update_animations(tick);
+        read();
+        let running = driver.has_active_animations();
+        if had_active_animations {
+            driver.set_has_active_animations();
+        }
+        running
+    })
+}
+
+/// Panics if the animation of the property that `read` reads has finished, or if the property
+/// isn't animated.
+///
+/// Slint animations don't run in real time in tests, so an animation only progresses when the
+/// mocked time advances, for example with [`assert_animation_value()`]. Only the animations that
+/// `read` evaluates are taken into account; use [`assert_in_transition()`] for the transitions
+/// between the states of an element.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <bool> moved;
+///         out property <length> rect-x: rect.x;
+///         rect := Rectangle {
+///             x: moved ? 100px : 0px;
+///             animate x { duration: 200ms; }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::assert_animation_finished(|| { app.get_rect_x(); });
+/// app.set_moved(true);
+/// i_slint_backend_testing::assert_animation_running(|| { app.get_rect_x(); });
+/// i_slint_backend_testing::assert_animation_value(100., 200, || app.get_rect_x());
+/// i_slint_backend_testing::assert_animation_finished(|| { app.get_rect_x(); });
+/// ```
+#[track_caller]
+pub fn assert_animation_running(read: impl FnOnce()) {
+    if !is_animation_running(read) {
+        panic!("The animation of the property has finished");
+    }
+}
+
+/// Panics if the animation of the property that `read` reads is still running.
+///
+/// See [`assert_animation_running()`].
+#[track_caller]
+pub fn assert_animation_finished(read: impl FnOnce()) {
+    if is_animation_running(read) {
+        panic!("The animation of the property is still running");
+    }
+}
+
+/// Panics if the state of `element` that currently applies isn't the one named `expected_state`,
+/// as declared in the `states` block of the element. Use an empty string to check that none of
+/// the states applies.
+///
+/// The state names require the debug information to be generated by the Slint compiler, see
+/// [`ElementHandle::find_by_element_id()`].
+#[track_caller]
+pub fn assert_state(element: &ElementHandle, expected_state: &str) {
+    let Some(state) = element.current_state() else {
+        panic!("The element has no states, or no debug information was generated");
+    };
+    if state != expected_state {
+        panic!("The current state of the element is {state:?}, expected {expected_state:?}");
+    }
+}
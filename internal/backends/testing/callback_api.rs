@@ -0,0 +1,217 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::search_api::find_descendant;
+use crate::ElementHandle;
+use core::pin::Pin;
+use i_slint_core::callbacks::Callback;
+use i_slint_core::items::{Flickable, FocusScope, ItemRc, ItemVTable, TextInput, TouchArea};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use vtable::HasStaticVTable;
+
+/// Counts how many times a callback of an element was emitted. Obtain it with
+/// [`record_signal()`].
+///
+/// The emissions are counted before the handler of the callback is invoked, and until the
+/// recorder is dropped.
+pub struct SignalRecorder {
+    count: Rc<Cell<usize>>,
+    _observer: CallbackObserverGuard,
+}
+
+impl SignalRecorder {
+    /// Returns how many times the callback was emitted.
+    pub fn count(&self) -> usize {
+        self.count.get()
+    }
+
+    /// Forgets the emissions counted so far.
+    pub fn clear(&self) {
+        self.count.set(0);
+    }
+}
+
+/// Starts counting the emissions of the callback named `name` of `element`, such as `clicked` or
+/// `edited`. Panics if there's no such callback.
+///
+/// The callback is one of the built-in callbacks of `TouchArea`, `FocusScope`, `Flickable`, or
+/// `TextInput`. If `element` doesn't have it, the first of its descendants that does is used, so
+/// that this function can be used with widgets such as `Button` or `LineEdit`. The handler of the
+/// callback is still invoked. Dropping the recorder restores the handler that was set when it was
+/// created, unless another handler was set since, which also stops the counting.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::platform::{PointerEventButton, WindowEvent};
+/// use slint::{ComponentHandle, LogicalPosition};
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 100px;
+///         height: 100px;
+///         TouchArea {
+///             accessible-role: button;
+///             accessible-label: "OK";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let ok = ElementHandle::find_by_accessible_label(&app, "OK").next().unwrap();
+/// let recorder = i_slint_backend_testing::record_signal(&ok, "clicked");
+/// let (position, button) = (LogicalPosition::new(50., 50.), PointerEventButton::Left);
+/// app.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+/// i_slint_backend_testing::assert_signal_emitted(&recorder, 0);
+/// app.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+/// i_slint_backend_testing::assert_signal_emitted(&recorder, 1);
+/// ```
+#[track_caller]
+pub fn record_signal(element: &ElementHandle, name: &str) -> SignalRecorder {
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    let Some(observer) = observe_callback(element, name, move || counter.set(counter.get() + 1))
+    else {
+        panic!("The element doesn't have, and doesn't contain, a callback named {name}");
+    };
+    SignalRecorder { count, _observer: observer }
+}
+
+/// Panics if the callback counted by `recorder` wasn't emitted exactly `expected` times since the
+/// recorder was created or cleared. See [`record_signal()`] for an example.
+#[track_caller]
+pub fn assert_signal_emitted(recorder: &SignalRecorder, expected: usize) {
+    let count = recorder.count();
+    if count != expected {
+        panic!("The callback was emitted {count} times, expected {expected}");
+    }
+}
+
+/// Calls `observer` each time the callback named `name` of `element`, or of its first descendant
+/// that has one, is emitted. Returns None if there's no such callback.
+pub(crate) fn observe_callback(
+    element: &ElementHandle,
+    name: &str,
+    observer: impl FnMut() + 'static,
+) -> Option<CallbackObserverGuard> {
+    let item = element.item_rc()?;
+    let observer = Rc::new(RefCell::new(observer));
+    let guard = RefCell::new(None);
+    let observe = |item: &ItemRc| {
+        let observer = observer.clone();
+        *guard.borrow_mut() = observe_item_callback(item, name, move || (*observer.borrow_mut())());
+        guard.borrow().is_some()
+    };
+    if !observe(&item) {
+        find_descendant(&item, &observe)?;
+    }
+    guard.into_inner()
+}
+
+/// Removes the observer installed with [`observe_callback()`] or [`observe_item_field()`] from its
+/// callback when dropped, if the element still exists.
+pub(crate) struct CallbackObserverGuard(Option<Box<dyn FnOnce()>>);
+
+impl Drop for CallbackObserverGuard {
+    fn drop(&mut self) {
+        if let Some(remove) = self.0.take() {
+            remove();
+        }
+    }
+}
+
+/// Calls `f` with the argument each time the callback that `field` returns of `item`, which must
+/// be a `T`, is emitted.
+pub(crate) fn observe_item_field<T, Arg, Ret>(
+    item: &ItemRc,
+    field: fn(Pin<&T>) -> Pin<&Callback<Arg, Ret>>,
+    f: impl FnMut(&Arg) + 'static,
+) -> CallbackObserverGuard
+where
+    T: HasStaticVTable<ItemVTable> + 'static,
+    Arg: ?Sized + 'static,
+    Ret: Default + 'static,
+{
+    let observer = field(item.downcast::<T>().unwrap().as_pin_ref()).observe(f);
+    let item = item.downgrade();
+    CallbackObserverGuard(Some(Box::new(move || {
+        if let Some(item) = item.upgrade().as_ref().and_then(ItemRc::downcast::<T>) {
+            field(item.as_pin_ref()).remove_observer(observer);
+        }
+    })))
+}
+
+/// Calls `f` each time the built-in callback named `name` of `item` is emitted. Returns None if
+/// `item` has no callback with that name.
+fn observe_item_callback(
+    item: &ItemRc,
+    name: &str,
+    mut f: impl FnMut() + 'static,
+) -> Option<CallbackObserverGuard> {
+    macro_rules! observe {
+        ($item_type:ident, $field:ident) => {
+            observe_item_field(
+                item,
+                |t| $item_type::FIELD_OFFSETS.$field.apply_pin(t),
+                move |_| f(),
+            )
+        };
+    }
+    let guard = if item.downcast::<TouchArea>().is_some() {
+        match name {
+            "clicked" => observe!(TouchArea, clicked),
+            "double-clicked" => observe!(TouchArea, double_clicked),
+            "moved" => observe!(TouchArea, moved),
+            "pointer-event" => observe!(TouchArea, pointer_event),
+            "scroll-event" => observe!(TouchArea, scroll_event),
+            _ => return None,
+        }
+    } else if item.downcast::<FocusScope>().is_some() {
+        match name {
+            "key-pressed" => observe!(FocusScope, key_pressed),
+            "key-released" => observe!(FocusScope, key_released),
+            "focus-changed-event" => observe!(FocusScope, focus_changed_event),
+            _ => return None,
+        }
+    } else if item.downcast::<Flickable>().is_some() && name == "flicked" {
+        observe!(Flickable, flicked)
+    } else if item.downcast::<TextInput>().is_some() {
+        match name {
+            "accepted" => observe!(TextInput, accepted),
+            "edited" => observe!(TextInput, edited),
+            "cursor-position-changed" => observe!(TextInput, cursor_position_changed),
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+    Some(guard)
+}
+
+/// Calls `invoke`, which invokes a callback of a component and returns its result, and panics if
+/// that result isn't `expected`.
+///
+/// Invoking a callback that has no handler returns the default value of its return type, such as
+/// `0` or an empty string, so prefer an `expected` value that differs from that default.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <int> tax-percent: 20;
+///         callback price-with-tax(int) -> int;
+///         price-with-tax(price) => { price + price * tax-percent / 100 }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::assert_callback_return_value(120, || app.invoke_price_with_tax(100));
+/// ```
+#[track_caller]
+pub fn assert_callback_return_value<T: PartialEq + core::fmt::Debug>(
+    expected: T,
+    invoke: impl FnOnce() -> T,
+) {
+    let actual = invoke();
+    if actual != expected {
+        panic!("The callback returned {actual:?}, expected {expected:?}");
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The content of a [`MockClipboard`].
+pub(crate) type MockClipboardText = Rc<RefCell<Option<String>>>;
+
+thread_local! {
+    /// The content of the MockClipboard that exist in this thread, in the order in which they
+    /// were installed. The last one replaces the clipboard of the testing backend.
+    static MOCK_CLIPBOARDS: RefCell<Vec<MockClipboardText>> = RefCell::new(Vec::new());
+}
+
+/// Returns the content of the mock clipboard that replaces the clipboard of the testing backend,
+/// if one is installed.
+pub(crate) fn installed_mock_clipboard() -> Option<MockClipboardText> {
+    MOCK_CLIPBOARDS.with(|mocks| mocks.borrow().last().cloned())
+}
+
+/// An empty in-memory clipboard that replaces the clipboard of the testing backend until it is
+/// dropped. Obtain it with [`install_mock_clipboard()`].
+///
+/// Elements such as `TextInput` copy to and paste from that clipboard, so that tests can check
+/// what was copied, or provide the text to paste. The clipboard of the testing backend is left
+/// untouched, and is used again once the MockClipboard is dropped. If several mock clipboards are
+/// installed, the last one that wasn't dropped is used.
+#[must_use = "the mock clipboard is removed when the MockClipboard is dropped"]
+pub struct MockClipboard {
+    text: MockClipboardText,
+}
+
+impl MockClipboard {
+    /// Returns the text in the clipboard, if any.
+    pub fn text(&self) -> Option<String> {
+        self.text.borrow().clone()
+    }
+
+    /// Puts `text` into the clipboard, as if it was copied from another application.
+    pub fn set_text(&self, text: &str) {
+        *self.text.borrow_mut() = Some(text.into());
+    }
+
+    /// Empties the clipboard.
+    pub fn clear(&self) {
+        *self.text.borrow_mut() = None;
+    }
+}
+
+impl Drop for MockClipboard {
+    fn drop(&mut self) {
+        MOCK_CLIPBOARDS
+            .with(|mocks| mocks.borrow_mut().retain(|text| !Rc::ptr_eq(text, &self.text)));
+    }
+}
+
+/// Replaces the clipboard of the testing backend with an empty one, until the returned
+/// [`MockClipboard`] is dropped.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in-out property <string> text <=> input.text;
+///         callback paste();
+///         paste => { input.paste(); }
+///         input := TextInput { }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let clipboard = i_slint_backend_testing::install_mock_clipboard();
+/// clipboard.set_text("Hello");
+/// app.invoke_paste();
+/// assert_eq!(app.get_text(), "Hello");
+/// ```
+pub fn install_mock_clipboard() -> MockClipboard {
+    let text = Rc::new(RefCell::new(None));
+    MOCK_CLIPBOARDS.with(|mocks| mocks.borrow_mut().push(text.clone()));
+    MockClipboard { text }
+}
+
+/// Puts `text` into the mock clipboard that was last installed with [`install_mock_clipboard()`],
+/// as if it was copied from another application.
+///
+/// This is the same as [`MockClipboard::set_text()`], for the parts of a test that don't have
+/// access to the `MockClipboard`. Panics if no mock clipboard is installed.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in-out property <string> text <=> input.text;
+///         callback paste();
+///         paste => { input.paste(); }
+///         input := TextInput { }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let _clipboard = i_slint_backend_testing::install_mock_clipboard();
+/// i_slint_backend_testing::inject_clipboard_text("Hello");
+/// app.invoke_paste();
+/// assert_eq!(app.get_text(), "Hello");
+/// ```
+#[track_caller]
+pub fn inject_clipboard_text(text: &str) {
+    let Some(mock) = installed_mock_clipboard() else {
+        panic!("No mock clipboard is installed, call install_mock_clipboard() first");
+    };
+    *mock.borrow_mut() = Some(text.into());
+}
@@ -2,9 +2,17 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
 
 use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::graphics::Brush;
 use i_slint_core::item_tree::{ItemTreeRc, ItemWeak};
+use i_slint_core::items::{
+    ColorScheme, ImageFit, ItemRc, MouseCursor, TextHorizontalAlignment, TextVerticalAlignment,
+    TextWrap,
+};
+use i_slint_core::lengths::{LogicalPoint, LogicalRect};
 use i_slint_core::slice::Slice;
-use i_slint_core::SharedVector;
+use i_slint_core::window::WindowAdapterRc;
+use i_slint_core::{SharedString, SharedVector};
+use std::ffi::c_void;
 
 #[no_mangle]
 pub extern "C" fn slint_testing_init_backend() {
@@ -22,3 +30,611 @@ pub extern "C" fn slint_testing_element_find_by_accessible_label(
         item.accessible_string_property(AccessibleStringProperty::Label).is_some_and(|x| x == label)
     })
 }
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_find_by_element_id(
+    root: &ItemTreeRc,
+    element_id: &Slice<u8>,
+    out: &mut SharedVector<ItemWeak>,
+) {
+    let Ok(element_id) = core::str::from_utf8(element_id.as_slice()) else { return };
+    *out = crate::search_api::search_item(root, |item| {
+        crate::search_api::item_has_element_id(item, element_id)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_dump_item_tree(root: &ItemTreeRc, out: &mut SharedString) {
+    let mut result = String::new();
+    crate::search_api::dump_item(&ItemRc::new(root.clone(), 0), 0, &mut result);
+    *out = result.into();
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_id_at_position(
+    root: &ItemTreeRc,
+    x: f32,
+    y: f32,
+    out: &mut SharedString,
+) -> bool {
+    match crate::search_api::item_tree_id_at_position(root, LogicalPoint::new(x, y)) {
+        Some(id) => {
+            *out = id;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_rendering_order(
+    root: &ItemTreeRc,
+    element_id: &Slice<u8>,
+    position: &mut usize,
+) -> bool {
+    let Ok(element_id) = core::str::from_utf8(element_id.as_slice()) else { return false };
+    match crate::search_api::rendering_order(root, element_id) {
+        Some(p) => {
+            *position = p;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_item_tree_depth(element: &ItemWeak, depth: &mut u32) -> bool {
+    let Some(item) = element.upgrade() else { return false };
+    *depth = crate::search_api::item_tree_depth(&item);
+    true
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_focus_has_element_id(
+    window_adapter: &WindowAdapterRc,
+    element_id: &Slice<u8>,
+) -> bool {
+    let Ok(element_id) = core::str::from_utf8(element_id.as_slice()) else { return false };
+    crate::input_api::focus_has_element_id(window_adapter.window(), element_id)
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_mouse_cursor(
+    window_adapter: &WindowAdapterRc,
+) -> MouseCursor {
+    crate::testing_backend::with_testing_window(window_adapter.window(), |w| w.mouse_cursor.get())
+        .unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_draw_if_needed(window_adapter: &WindowAdapterRc) -> bool {
+    crate::testing_backend::with_testing_window(window_adapter.window(), |w| w.draw_if_needed())
+        .unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_rendered_frames(window_adapter: &WindowAdapterRc) -> u32 {
+    crate::testing_backend::with_testing_window(window_adapter.window(), |w| w.rendered_frames())
+        .unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_painting_rect(
+    window_adapter: &WindowAdapterRc,
+    element: &ItemWeak,
+    out: &mut LogicalRect,
+) -> bool {
+    let rect = crate::testing_backend::with_testing_window(window_adapter.window(), |w| {
+        w.draw_if_needed();
+        w.painting_rect(element)
+    });
+    match rect.flatten() {
+        Some(rect) => {
+            *out = rect;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_repaint_region(
+    window_adapter: &WindowAdapterRc,
+    out: &mut LogicalRect,
+) -> bool {
+    let rect = crate::testing_backend::with_testing_window(window_adapter.window(), |w| {
+        if w.draw_if_needed() {
+            w.repaint_region()
+        } else {
+            Default::default()
+        }
+    });
+    match rect {
+        Some(rect) => {
+            *out = rect;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_clipping_rect(element: &ItemWeak, out: &mut LogicalRect) -> bool {
+    match element.upgrade().and_then(|item| crate::rendering_api::clipping_rect(&item)) {
+        Some(rect) => {
+            *out = rect;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_opacity(element: &ItemWeak, out: &mut f32) -> bool {
+    match element.upgrade() {
+        Some(item) => {
+            *out = crate::rendering_api::opacity(&item);
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_rotation_angle(element: &ItemWeak, out: &mut f32) -> bool {
+    match element.upgrade() {
+        Some(item) => {
+            *out = crate::rendering_api::rotation_angle(&item);
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_drop_shadow(
+    element: &ItemWeak,
+    blur: &mut f32,
+    offset_x: &mut f32,
+    offset_y: &mut f32,
+    argb_color: &mut u32,
+) -> bool {
+    match element.upgrade() {
+        Some(item) => {
+            let (shadow_blur, shadow_offset_x, shadow_offset_y, shadow_color) =
+                crate::rendering_api::drop_shadow(&item);
+            *blur = shadow_blur;
+            *offset_x = shadow_offset_x;
+            *offset_y = shadow_offset_y;
+            *argb_color = shadow_color.as_argb_encoded();
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_element_border_radius(
+    element: &ItemWeak,
+    top_left: &mut f32,
+    top_right: &mut f32,
+    bottom_right: &mut f32,
+    bottom_left: &mut f32,
+) -> bool {
+    match element.upgrade().and_then(|item| crate::rendering_api::border_radius(&item)) {
+        Some(radius) => {
+            *top_left = radius.top_left;
+            *top_right = radius.top_right;
+            *bottom_right = radius.bottom_right;
+            *bottom_left = radius.bottom_left;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_font_family(element: &ItemWeak, out: &mut SharedString) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::text_api::font_family(&element) {
+        Some(family) => {
+            *out = family;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_font_size(
+    element: &ItemWeak,
+    has_size: &mut bool,
+    out: &mut f32,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::text_api::font_size(&element) {
+        Some(size) => {
+            *has_size = size.is_some();
+            *out = size.unwrap_or_default();
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_text_alignment(
+    element: &ItemWeak,
+    horizontal: &mut TextHorizontalAlignment,
+    vertical: &mut TextVerticalAlignment,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::text_api::text_alignment(&element) {
+        Some(alignment) => {
+            (*horizontal, *vertical) = alignment;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_text_wrap(element: &ItemWeak, out: &mut TextWrap) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::text_api::text_wrap(&element) {
+        Some(wrap) => {
+            *out = wrap;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_letter_spacing(element: &ItemWeak, out: &mut f32) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::text_api::letter_spacing(&element) {
+        Some(spacing) => {
+            *out = spacing;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_stroke_width(element: &ItemWeak, out: &mut f32) -> bool {
+    match element.upgrade().and_then(|item| crate::rendering_api::stroke_width(&item)) {
+        Some(width) => {
+            *out = width;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_linear_gradient_stops(
+    element: &ItemWeak,
+    property: &Slice<u8>,
+    argb_colors: &mut SharedVector<u32>,
+    positions: &mut SharedVector<f32>,
+) -> bool {
+    let Ok(property) = core::str::from_utf8(property.as_slice()) else { return false };
+    let brush =
+        element.upgrade().and_then(|item| crate::rendering_api::brush_property(&item, property));
+    match brush {
+        Some(Brush::LinearGradient(gradient)) => {
+            *argb_colors = gradient.stops().map(|stop| stop.color.as_argb_encoded()).collect();
+            *positions = gradient.stops().map(|stop| stop.position).collect();
+            true
+        }
+        _ => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_touch_area_pressed(element: &ItemWeak, out: &mut bool) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::input_api::touch_area_pressed(&element) {
+        Some(pressed) => {
+            *out = pressed;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_pointer_position(
+    element: &ItemWeak,
+    x: &mut f32,
+    y: &mut f32,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::input_api::pointer_position(&element) {
+        Some(position) => {
+            (*x, *y) = position;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_observe_key_presses(
+    element: &ItemWeak,
+    observer: extern "C" fn(user_data: *mut c_void, text: &SharedString),
+    user_data: *mut c_void,
+    drop_user_data: extern "C" fn(user_data: *mut c_void),
+) -> *mut c_void {
+    struct UserData {
+        user_data: *mut c_void,
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        observer: extern "C" fn(user_data: *mut c_void, text: &SharedString),
+    }
+    impl Drop for UserData {
+        fn drop(&mut self) {
+            (self.drop_user_data)(self.user_data)
+        }
+    }
+    impl UserData {
+        fn call(&self, text: &SharedString) {
+            (self.observer)(self.user_data, text)
+        }
+    }
+    let ud = UserData { user_data, drop_user_data, observer };
+    let element = crate::ElementHandle::new(element.clone());
+    let observer = crate::input_api::observe_key_presses(&element, move |text| ud.call(text));
+    observer.map_or(core::ptr::null_mut(), |o| Box::into_raw(Box::new(o)) as *mut c_void)
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_observe_callback(
+    element: &ItemWeak,
+    name: &Slice<u8>,
+    observer: extern "C" fn(user_data: *mut c_void),
+    user_data: *mut c_void,
+    drop_user_data: extern "C" fn(user_data: *mut c_void),
+) -> *mut c_void {
+    struct UserData {
+        user_data: *mut c_void,
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        observer: extern "C" fn(user_data: *mut c_void),
+    }
+    impl Drop for UserData {
+        fn drop(&mut self) {
+            (self.drop_user_data)(self.user_data)
+        }
+    }
+    impl UserData {
+        fn call(&self) {
+            (self.observer)(self.user_data)
+        }
+    }
+    let ud = UserData { user_data, drop_user_data, observer };
+    let Ok(name) = core::str::from_utf8(name.as_slice()) else { return core::ptr::null_mut() };
+    let element = crate::ElementHandle::new(element.clone());
+    let observer = crate::callback_api::observe_callback(&element, name, move || ud.call());
+    observer.map_or(core::ptr::null_mut(), |o| Box::into_raw(Box::new(o)) as *mut c_void)
+}
+
+/// Removes an observer returned by slint_testing_observe_key_presses or
+/// slint_testing_observe_callback from its callback.
+#[no_mangle]
+pub unsafe extern "C" fn slint_testing_drop_callback_observer(observer: *mut c_void) {
+    if !observer.is_null() {
+        drop(Box::from_raw(observer as *mut crate::callback_api::CallbackObserverGuard));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_combobox_items(
+    window_adapter: &WindowAdapterRc,
+    combobox: &ItemWeak,
+    out: &mut SharedVector<SharedString>,
+) -> bool {
+    let combobox = crate::ElementHandle::new(combobox.clone());
+    match crate::widget_api::combobox_items(window_adapter.window(), &combobox) {
+        Some(items) => {
+            *out = items.into_iter().collect();
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_tab_widget_current_index(
+    tab_widget: &ItemWeak,
+    out: &mut i32,
+) -> bool {
+    let tab_widget = crate::ElementHandle::new(tab_widget.clone());
+    match crate::widget_api::tab_widget_current_index(&tab_widget) {
+        Some(index) => {
+            *out = index;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_text_selection(
+    element: &ItemWeak,
+    start: &mut usize,
+    end: &mut usize,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::widget_api::text_selection(&element) {
+        Some(selection) => {
+            (*start, *end) = selection;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_scroll_position(
+    element: &ItemWeak,
+    x: &mut f32,
+    y: &mut f32,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::widget_api::scroll_position(&element) {
+        Some(position) => {
+            (*x, *y) = position;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_image_size(
+    element: &ItemWeak,
+    width: &mut f32,
+    height: &mut f32,
+) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::widget_api::image_size(&element) {
+        Some(size) => {
+            (*width, *height) = size;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_image_fit(element: &ItemWeak, out: &mut ImageFit) -> bool {
+    let element = crate::ElementHandle::new(element.clone());
+    match crate::widget_api::image_fit(&element) {
+        Some(fit) => {
+            *out = fit;
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_is_animation_running(
+    read: extern "C" fn(user_data: *mut c_void),
+    user_data: *mut c_void,
+) -> bool {
+    crate::animation_api::is_animation_running(|| read(user_data))
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_set_color_scheme(
+    window_adapter: &WindowAdapterRc,
+    scheme: ColorScheme,
+) {
+    crate::testing_backend::with_testing_window(window_adapter.window(), |w| {
+        w.set_color_scheme(scheme)
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_compute_layouts(window_adapter: &WindowAdapterRc) {
+    crate::window_api::compute_layouts(window_adapter.window());
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_compositing_layers(window_adapter: &WindowAdapterRc) -> u32 {
+    crate::rendering_api::compositing_layers(window_adapter.window())
+}
+
+#[cfg(feature = "debug-counters")]
+#[no_mangle]
+pub extern "C" fn slint_testing_binding_evaluation_count() -> u32 {
+    i_slint_core::properties::binding_evaluation_count()
+}
+
+#[cfg(feature = "debug-counters")]
+#[no_mangle]
+pub extern "C" fn slint_testing_layout_solve_count() -> u32 {
+    i_slint_core::layout::solve_count()
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_live_window_count() -> usize {
+    crate::testing_backend::live_window_count()
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_clear_image_cache() {
+    i_slint_core::graphics::cache::clear();
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_size(
+    window_adapter: &WindowAdapterRc,
+    width: &mut f32,
+    height: &mut f32,
+) {
+    let size = crate::window_api::window_size(window_adapter.window());
+    (*width, *height) = (size.width, size.height);
+}
+
+#[no_mangle]
+pub extern "C" fn slint_testing_window_title(
+    window_adapter: &WindowAdapterRc,
+    out: &mut SharedString,
+) -> bool {
+    i_slint_core::window::WindowInner::from_pub(window_adapter.window()).update_window_properties();
+    match crate::testing_backend::with_testing_window(window_adapter.window(), |w| w.title()) {
+        Some(title) => {
+            *out = title;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns a MockClipboard, which must be destroyed with slint_testing_mock_clipboard_drop.
+#[no_mangle]
+pub extern "C" fn slint_testing_install_mock_clipboard() -> *mut c_void {
+    Box::into_raw(Box::new(crate::install_mock_clipboard())) as *mut c_void
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn slint_testing_mock_clipboard_drop(mock: *mut c_void) {
+    drop(Box::from_raw(mock as *mut crate::MockClipboard));
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn slint_testing_mock_clipboard_text(
+    mock: *const c_void,
+    out: &mut SharedString,
+) -> bool {
+    match (*(mock as *const crate::MockClipboard)).text() {
+        Some(text) => {
+            *out = text.into();
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn slint_testing_mock_clipboard_set_text(
+    mock: *const c_void,
+    text: Option<&SharedString>,
+) {
+    let mock = &*(mock as *const crate::MockClipboard);
+    match text {
+        Some(text) => mock.set_text(text),
+        None => mock.clear(),
+    }
+}
+
+/// Returns false if no mock clipboard is installed.
+#[no_mangle]
+pub extern "C" fn slint_testing_inject_clipboard_text(text: &SharedString) -> bool {
+    let Some(mock) = crate::clipboard_api::installed_mock_clipboard() else { return false };
+    *mock.borrow_mut() = Some(text.as_str().into());
+    true
+}
@@ -0,0 +1,387 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::callback_api::{observe_item_field, CallbackObserverGuard};
+use crate::search_api::item_has_element_id;
+use crate::testing_backend::with_testing_window;
+use crate::widget_api::find_self_or_descendant;
+use crate::ElementHandle;
+use i_slint_core::api::{ComponentHandle, LogicalPosition};
+pub use i_slint_core::items::MouseCursor;
+use i_slint_core::items::{FocusScope, TouchArea};
+use i_slint_core::platform::{Key, PointerEventButton, WindowEvent};
+use i_slint_core::window::WindowInner;
+use i_slint_core::SharedString;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Simulates the user pressing the keys of a shortcut such as <kbd>Ctrl</kbd>+<kbd>S</kbd> in the
+/// window of `component`.
+///
+/// The keys are pressed in the order of `keys`, and then released in the reverse order, so that
+/// modifiers stay pressed until the last key was released. Each key is either a character or one
+/// of the special keys of [`slint::platform::Key`](i_slint_core::platform::Key).
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{platform::Key, SharedString};
+/// slint::slint!{
+///     export component App inherits Window {
+///         out property <int> saved;
+///         forward-focus: scope;
+///         scope := FocusScope {
+///             key-pressed(event) => {
+///                 if (event.modifiers.control && event.text == "s") {
+///                     saved += 1;
+///                 }
+///                 accept
+///             }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::simulate_key_sequence(&app, [SharedString::from(Key::Control), "s".into()]);
+/// assert_eq!(app.get_saved(), 1);
+/// ```
+pub fn simulate_key_sequence(
+    component: &impl ComponentHandle,
+    keys: impl IntoIterator<Item = impl Into<SharedString>>,
+) {
+    let window = component.window();
+    let keys = keys.into_iter().map(Into::into).collect::<Vec<SharedString>>();
+    for text in keys.iter() {
+        window.dispatch_event(WindowEvent::KeyPressed { text: text.clone() });
+    }
+    for text in keys.into_iter().rev() {
+        window.dispatch_event(WindowEvent::KeyReleased { text });
+    }
+}
+
+/// Returns true if the item that has the keyboard focus in `window`, or one of its ancestors,
+/// was declared with the id `element_id`.
+pub(crate) fn focus_has_element_id(window: &i_slint_core::api::Window, element_id: &str) -> bool {
+    let focus_item = WindowInner::from_pub(window).focus_item.borrow().upgrade();
+    std::iter::successors(focus_item, |item| item.parent_item())
+        .any(|item| item_has_element_id(&item, element_id))
+}
+
+/// Simulates pressing the <kbd>Tab</kbd> key once for each entry of `element_ids`, and panics if
+/// the keyboard focus doesn't move to the element with that id.
+///
+/// The ids must be qualified with the name of the component, as in
+/// [`ElementHandle::find_by_element_id()`]. An element also counts as focused when the focus is
+/// on one of its children, so that this function can be used with widgets such as `LineEdit`.
+#[track_caller]
+pub fn assert_tab_order(component: &impl ComponentHandle, element_ids: &[&str]) {
+    let window = component.window();
+    for (press, element_id) in element_ids.iter().enumerate() {
+        simulate_key_sequence(component, [Key::Tab]);
+        if !focus_has_element_id(window, element_id) {
+            panic!(
+                "After {} Tab key press(es), expected the focus on {element_id}, but it is on {}",
+                press + 1,
+                focused_element_id(window).as_deref().unwrap_or("an element without id")
+            );
+        }
+    }
+}
+
+/// Returns the id of the item that has the keyboard focus in `window`, or of its closest ancestor
+/// that has an id.
+fn focused_element_id(window: &i_slint_core::api::Window) -> Option<SharedString> {
+    let focus_item = WindowInner::from_pub(window).focus_item.borrow().upgrade();
+    std::iter::successors(focus_item, |item| item.parent_item())
+        .find_map(|item| ElementHandle::new(item.downgrade()).id())
+}
+
+/// Panics if the keyboard focus in the window of `component` isn't on the element with the id
+/// `expected_id`.
+///
+/// The id must be qualified with the name of the component, as in
+/// [`ElementHandle::find_by_element_id()`]. An element also counts as focused when the focus is
+/// on one of its children, so that this function can be used with widgets such as `LineEdit`.
+#[track_caller]
+pub fn assert_focus_element(component: &impl ComponentHandle, expected_id: &str) {
+    let window = component.window();
+    if !focus_has_element_id(window, expected_id) {
+        panic!(
+            "Expected the focus on {expected_id}, but it is on {}",
+            focused_element_id(window).as_deref().unwrap_or("an element without id")
+        );
+    }
+}
+
+/// Returns the center of `element`, in logical window coordinates.
+pub(crate) fn element_center(element: &ElementHandle) -> LogicalPosition {
+    let (position, size) = (element.absolute_position(), element.size());
+    LogicalPosition::new(position.x + size.width / 2., position.y + size.height / 2.)
+}
+
+/// Simulates a click with the left mouse button at `position` in `window`.
+pub(crate) fn simulate_click(window: &i_slint_core::api::Window, position: LogicalPosition) {
+    let button = PointerEventButton::Left;
+    window.dispatch_event(WindowEvent::PointerMoved { position });
+    window.dispatch_event(WindowEvent::PointerPressed { position, button });
+    window.dispatch_event(WindowEvent::PointerReleased { position, button });
+}
+
+/// Simulates the user moving the mouse over the center of `element`, and panics if the mouse
+/// cursor of the window of `component` then isn't `expected`.
+///
+/// The cursor is the one that the element under the mouse requests, for example with the
+/// `mouse-cursor` property of a `TouchArea`, or the text cursor of a `TextInput`.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::{ElementHandle, MouseCursor};
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 100px;
+///         height: 100px;
+///         TouchArea {
+///             mouse-cursor: pointer;
+///             accessible-role: button;
+///             accessible-label: "Link";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let link = ElementHandle::find_by_accessible_label(&app, "Link").next().unwrap();
+/// i_slint_backend_testing::assert_cursor_shape(&app, &link, MouseCursor::Pointer);
+/// ```
+#[track_caller]
+pub fn assert_cursor_shape(
+    component: &impl ComponentHandle,
+    element: &ElementHandle,
+    expected: MouseCursor,
+) {
+    if !element.is_valid() {
+        panic!("The element doesn't exist anymore");
+    }
+    let window = component.window();
+    window.dispatch_event(WindowEvent::PointerMoved { position: element_center(element) });
+    let Some(cursor) = with_testing_window(window, |w| w.mouse_cursor.get()) else {
+        panic!("The window of the component wasn't created by the testing backend");
+    };
+    if cursor != expected {
+        panic!("The mouse cursor over the element is {cursor:?}, expected {expected:?}");
+    }
+}
+
+/// Returns the `pressed` property of `element`, which is either a `TouchArea` or a widget that
+/// contains one, such as `Button`.
+pub(crate) fn touch_area_pressed(element: &ElementHandle) -> Option<bool> {
+    let item = find_self_or_descendant::<TouchArea>(element)?;
+    Some(item.downcast::<TouchArea>()?.as_pin_ref().pressed())
+}
+
+/// Panics if the `pressed` property of `element` is false.
+///
+/// The element is either a `TouchArea` or a widget that contains one, such as `Button`. It is
+/// pressed from the moment a mouse button is pressed over it, until that button is released.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::platform::{PointerEventButton, WindowEvent};
+/// use slint::{ComponentHandle, LogicalPosition};
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 100px;
+///         height: 100px;
+///         TouchArea {
+///             accessible-role: button;
+///             accessible-label: "OK";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let ok = ElementHandle::find_by_accessible_label(&app, "OK").next().unwrap();
+/// let (position, button) = (LogicalPosition::new(50., 50.), PointerEventButton::Left);
+/// app.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+/// i_slint_backend_testing::assert_touch_area_pressed(&ok);
+/// app.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+/// i_slint_backend_testing::assert_touch_area_released(&ok);
+/// ```
+#[track_caller]
+pub fn assert_touch_area_pressed(element: &ElementHandle) {
+    match touch_area_pressed(element) {
+        None => panic!("The element is not, and doesn't contain, a TouchArea"),
+        Some(false) => panic!("The touch area is released, expected it to be pressed"),
+        Some(true) => {}
+    }
+}
+
+/// Panics if the `pressed` property of `element` is true. See [`assert_touch_area_pressed()`].
+#[track_caller]
+pub fn assert_touch_area_released(element: &ElementHandle) {
+    match touch_area_pressed(element) {
+        None => panic!("The element is not, and doesn't contain, a TouchArea"),
+        Some(true) => panic!("The touch area is pressed, expected it to be released"),
+        Some(false) => {}
+    }
+}
+
+/// Returns the `mouse-x` and `mouse-y` properties of `element`, which is either a `TouchArea` or
+/// a widget that contains one.
+pub(crate) fn pointer_position(element: &ElementHandle) -> Option<(f32, f32)> {
+    let item = find_self_or_descendant::<TouchArea>(element)?;
+    let touch_area = item.downcast::<TouchArea>()?;
+    let touch_area = touch_area.as_pin_ref();
+    Some((touch_area.mouse_x().get(), touch_area.mouse_y().get()))
+}
+
+/// Panics if the `mouse-x` property of `element` doesn't match `expected`, within 0.01 logical
+/// pixels.
+///
+/// The element is either a `TouchArea` or a widget that contains one. The position is relative to
+/// the touch area, and is the last one at which the mouse moved over it, or was dragged while
+/// pressed on it.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::platform::WindowEvent;
+/// use slint::{ComponentHandle, LogicalPosition};
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 200px;
+///         height: 200px;
+///         TouchArea {
+///             x: 50px;
+///             y: 50px;
+///             width: 100px;
+///             height: 100px;
+///             accessible-role: slider;
+///             accessible-label: "Color wheel";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let wheel = ElementHandle::find_by_accessible_label(&app, "Color wheel").next().unwrap();
+/// let position = LogicalPosition::new(60., 80.);
+/// app.window().dispatch_event(WindowEvent::PointerMoved { position });
+/// i_slint_backend_testing::assert_pointer_x(&wheel, 10.);
+/// i_slint_backend_testing::assert_pointer_y(&wheel, 30.);
+/// ```
+#[track_caller]
+pub fn assert_pointer_x(element: &ElementHandle, expected: f32) {
+    let Some((x, _)) = pointer_position(element) else {
+        panic!("The element is not, and doesn't contain, a TouchArea");
+    };
+    if (x - expected).abs() > 0.01 {
+        panic!("The mouse-x of the touch area is {x}, expected {expected}");
+    }
+}
+
+/// Panics if the `mouse-y` property of `element` doesn't match `expected`, within 0.01 logical
+/// pixels. See [`assert_pointer_x()`].
+#[track_caller]
+pub fn assert_pointer_y(element: &ElementHandle, expected: f32) {
+    let Some((_, y)) = pointer_position(element) else {
+        panic!("The element is not, and doesn't contain, a TouchArea");
+    };
+    if (y - expected).abs() > 0.01 {
+        panic!("The mouse-y of the touch area is {y}, expected {expected}");
+    }
+}
+
+/// Records the keys that are pressed while a `FocusScope` has the keyboard focus, or one of its
+/// children has the focus and doesn't accept the key. Obtain it with [`record_key_presses()`].
+///
+/// The key presses are recorded before the `key-pressed` callback of the `FocusScope` is invoked,
+/// whether or not that callback accepts them, and until the recorder is dropped.
+pub struct KeyPressRecorder {
+    keys: Rc<RefCell<Vec<SharedString>>>,
+    _observer: CallbackObserverGuard,
+}
+
+impl KeyPressRecorder {
+    /// Returns the text of the recorded key presses, in the order in which they were pressed.
+    pub fn keys(&self) -> Vec<SharedString> {
+        self.keys.borrow().clone()
+    }
+
+    /// Returns how many times the key with the text `key` was pressed.
+    pub fn count(&self, key: &str) -> usize {
+        self.keys.borrow().iter().filter(|text| text.as_str() == key).count()
+    }
+
+    /// Forgets the key presses recorded so far.
+    pub fn clear(&self) {
+        self.keys.borrow_mut().clear();
+    }
+}
+
+/// Starts recording the keys that are pressed in `element`, which is either a `FocusScope` or a
+/// widget that contains one. Panics if there's no `FocusScope`.
+///
+/// Dropping the recorder restores the `key-pressed` handler that was set when it was created,
+/// unless another handler was set since, which also stops the recording.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::{platform::Key, SharedString};
+/// slint::slint!{
+///     export component App inherits Window {
+///         forward-focus: scope;
+///         scope := FocusScope {
+///             accessible-role: text-input;
+///             accessible-label: "Terminal";
+///             key-pressed(event) => { accept }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let terminal = ElementHandle::find_by_accessible_label(&app, "Terminal").next().unwrap();
+/// let recorder = i_slint_backend_testing::record_key_presses(&terminal);
+/// i_slint_backend_testing::simulate_key_sequence(&app, ["l", "s", "\n"]);
+/// i_slint_backend_testing::simulate_key_sequence(&app, [SharedString::from(Key::Return)]);
+/// i_slint_backend_testing::assert_key_pressed_count(&recorder, "l", 1);
+/// i_slint_backend_testing::assert_key_pressed_count(&recorder, Key::Return, 2);
+/// ```
+#[track_caller]
+pub fn record_key_presses(element: &ElementHandle) -> KeyPressRecorder {
+    let keys = Rc::new(RefCell::new(Vec::new()));
+    let recorded = keys.clone();
+    let Some(observer) = observe_key_presses(element, move |text| {
+        recorded.borrow_mut().push(text.clone());
+    }) else {
+        panic!("The element is not, and doesn't contain, a FocusScope");
+    };
+    KeyPressRecorder { keys, _observer: observer }
+}
+
+/// Calls `observer` with the text of each key that is pressed in the `FocusScope` of `element`,
+/// until the returned guard is dropped. Returns None if `element` is not, and doesn't contain, a
+/// `FocusScope`.
+pub(crate) fn observe_key_presses(
+    element: &ElementHandle,
+    mut observer: impl FnMut(&SharedString) + 'static,
+) -> Option<CallbackObserverGuard> {
+    let item = find_self_or_descendant::<FocusScope>(element)?;
+    Some(observe_item_field(
+        &item,
+        |f| FocusScope::FIELD_OFFSETS.key_pressed.apply_pin(f),
+        move |(event,)| observer(&event.text),
+    ))
+}
+
+/// Panics if the key with the text `key` wasn't pressed exactly `expected` times since `recorder`
+/// was created or cleared.
+///
+/// The text of a special key such as [`Key::Return`] is the same as that of the character that it
+/// represents, so both are counted together. See [`record_key_presses()`] for an example.
+#[track_caller]
+pub fn assert_key_pressed_count(
+    recorder: &KeyPressRecorder,
+    key: impl Into<SharedString>,
+    expected: usize,
+) {
+    let key = key.into();
+    let count = recorder.count(&key);
+    if count != expected {
+        panic!("The key {key:?} was pressed {count} times, expected {expected}");
+    }
+}
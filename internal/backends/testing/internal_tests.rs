@@ -73,10 +73,6 @@ pub fn access_testing_window<R>(
     window: &i_slint_core::api::Window,
     callback: impl FnOnce(&TestingWindow) -> R,
 ) -> R {
-    i_slint_core::window::WindowInner::from_pub(window)
-        .window_adapter()
-        .internal(i_slint_core::InternalToken)
-        .and_then(|wa| wa.as_any().downcast_ref::<TestingWindow>())
-        .map(callback)
+    crate::testing_backend::with_testing_window(window, callback)
         .expect("access_testing_window called without testing backend/adapter")
 }
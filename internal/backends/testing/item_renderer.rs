@@ -0,0 +1,212 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+//! An item renderer that doesn't produce any pixels. It visits the items like a real
+//! renderer would, so that the dirty tracking of the window behaves like with a real backend.
+
+use i_slint_core::item_rendering::{
+    CachedRenderingData, ItemRenderer, RenderBorderRectangle, RenderImage,
+};
+use i_slint_core::item_tree::ItemWeak;
+use i_slint_core::items::{self, ItemRc};
+use i_slint_core::lengths::{
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
+};
+use i_slint_core::window::WindowInner;
+use std::cell::RefCell;
+use std::pin::Pin;
+
+#[derive(Clone, Copy)]
+struct RenderState {
+    translation: LogicalVector,
+    /// The clip, in the current (translated) coordinate system
+    clip: LogicalRect,
+}
+
+pub struct TestingItemRenderer<'a> {
+    window: &'a WindowInner,
+    state: RenderState,
+    state_stack: Vec<RenderState>,
+    /// The rectangle, in window coordinates, in which each item was last painted
+    painting_rects: &'a RefCell<Vec<(ItemWeak, LogicalRect)>>,
+}
+
+impl<'a> TestingItemRenderer<'a> {
+    pub fn new(
+        window: &'a WindowInner,
+        size: LogicalSize,
+        painting_rects: &'a RefCell<Vec<(ItemWeak, LogicalRect)>>,
+    ) -> Self {
+        Self {
+            window,
+            state: RenderState {
+                translation: Default::default(),
+                clip: LogicalRect::new(LogicalPoint::default(), size),
+            },
+            state_stack: Vec::new(),
+            painting_rects,
+        }
+    }
+
+    fn record_painting_rect(&self, item: &ItemRc, size: LogicalSize) {
+        let rect = LogicalRect::new(LogicalPoint::default() + self.state.translation, size);
+        let item = item.downgrade();
+        let mut painting_rects = self.painting_rects.borrow_mut();
+        match painting_rects.iter_mut().find(|(i, _)| *i == item) {
+            Some((_, r)) => *r = rect,
+            None => painting_rects.push((item, rect)),
+        }
+    }
+}
+
+// The draw functions access the properties that a real renderer would use, so that any change to
+// them marks the item as dirty and requests a redraw of the window.
+impl<'a> ItemRenderer for TestingItemRenderer<'a> {
+    fn draw_rectangle(&mut self, rect: Pin<&items::Rectangle>, item: &ItemRc, size: LogicalSize) {
+        self.record_painting_rect(item, size);
+        rect.background();
+    }
+
+    fn draw_border_rectangle(
+        &mut self,
+        rect: Pin<&dyn RenderBorderRectangle>,
+        item: &ItemRc,
+        size: LogicalSize,
+        _: &CachedRenderingData,
+    ) {
+        self.record_painting_rect(item, size);
+        rect.background();
+        rect.border_width();
+        rect.border_radius();
+        rect.border_color();
+    }
+
+    fn draw_image(
+        &mut self,
+        image: Pin<&dyn RenderImage>,
+        item: &ItemRc,
+        size: LogicalSize,
+        _: &CachedRenderingData,
+    ) {
+        self.record_painting_rect(item, size);
+        image.source();
+        image.source_clip();
+        image.image_fit();
+        image.rendering();
+        image.colorize();
+        image.alignment();
+        image.tiling();
+    }
+
+    fn draw_text(&mut self, text: Pin<&items::Text>, item: &ItemRc, size: LogicalSize) {
+        self.record_painting_rect(item, size);
+        text.text();
+        text.font_request(self.window);
+        text.color();
+        text.horizontal_alignment();
+        text.vertical_alignment();
+        text.wrap();
+        text.overflow();
+        text.stroke();
+        text.stroke_width();
+        text.stroke_style();
+    }
+
+    fn draw_text_input(
+        &mut self,
+        text_input: Pin<&items::TextInput>,
+        item: &ItemRc,
+        size: LogicalSize,
+    ) {
+        self.record_painting_rect(item, size);
+        text_input.visual_representation(None);
+        text_input.font_request(&self.window.window_adapter());
+        text_input.horizontal_alignment();
+        text_input.vertical_alignment();
+        text_input.wrap();
+    }
+
+    fn draw_path(&mut self, path: Pin<&items::Path>, item: &ItemRc, size: LogicalSize) {
+        self.record_painting_rect(item, size);
+        path.elements();
+        path.fill();
+        path.fill_rule();
+        path.stroke();
+        path.stroke_width();
+    }
+
+    fn draw_box_shadow(
+        &mut self,
+        box_shadow: Pin<&items::BoxShadow>,
+        item: &ItemRc,
+        size: LogicalSize,
+    ) {
+        self.record_painting_rect(item, size);
+        box_shadow.color();
+        box_shadow.offset_x();
+        box_shadow.offset_y();
+        box_shadow.blur();
+        box_shadow.border_radius();
+    }
+
+    fn combine_clip(
+        &mut self,
+        rect: LogicalRect,
+        _radius: LogicalBorderRadius,
+        _border_width: LogicalLength,
+    ) -> bool {
+        self.state.clip = self.state.clip.intersection(&rect).unwrap_or_default();
+        !self.state.clip.is_empty()
+    }
+
+    fn get_current_clip(&self) -> LogicalRect {
+        self.state.clip
+    }
+
+    fn translate(&mut self, distance: LogicalVector) {
+        self.state.translation += distance;
+        self.state.clip = self.state.clip.translate(-distance);
+    }
+
+    fn translation(&self) -> LogicalVector {
+        self.state.translation
+    }
+
+    fn rotate(&mut self, _angle_in_degrees: f32) {}
+
+    fn apply_opacity(&mut self, _opacity: f32) {}
+
+    fn save_state(&mut self) {
+        self.state_stack.push(self.state);
+    }
+
+    fn restore_state(&mut self) {
+        if let Some(state) = self.state_stack.pop() {
+            self.state = state;
+        }
+    }
+
+    fn scale_factor(&self) -> f32 {
+        self.window.scale_factor()
+    }
+
+    fn draw_cached_pixmap(
+        &mut self,
+        _item: &ItemRc,
+        update_fn: &dyn Fn(&mut dyn FnMut(u32, u32, &[u8])),
+    ) {
+        update_fn(&mut |_, _, _| {});
+    }
+
+    fn draw_string(&mut self, _string: &str, _color: i_slint_core::Color) {}
+
+    fn draw_image_direct(&mut self, _image: i_slint_core::graphics::Image) {}
+
+    fn window(&self) -> &WindowInner {
+        self.window
+    }
+
+    fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::testing_backend::live_window_count;
+
+/// Runs `body` and panics if a window that was created while doing so still exists afterwards.
+///
+/// A component owns the window of the testing backend that it is shown in, so a window that
+/// outlives `body` means that its component was leaked, typically because of a reference cycle
+/// between the component and one of its callbacks. Use a weak handle, as obtained with
+/// `as_weak()`, to access the component from its own callbacks.
+///
+/// Components create their window lazily, the first time it is needed, for example when calling
+/// `show()` or `window()`. A component whose window was never created is not checked. Note that
+/// a window that is visible keeps its component alive until `hide()` is called.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         callback clicked();
+///     }
+/// }
+/// i_slint_backend_testing::with_leak_check(|| {
+///     let app = App::new().unwrap();
+///     app.show().unwrap();
+///     let app_weak = app.as_weak();
+///     app.on_clicked(move || drop(app_weak.upgrade()));
+///     app.invoke_clicked();
+///     app.hide().unwrap();
+/// });
+/// ```
+#[track_caller]
+pub fn with_leak_check(body: impl FnOnce()) {
+    let start = live_window_count();
+    body();
+    let leaked = live_window_count().saturating_sub(start);
+    if leaked > 0 {
+        panic!("{leaked} window(s) created during the leak check still exist: a component leaked");
+    }
+}
@@ -4,6 +4,23 @@
 #![doc = include_str!("README.md")]
 #![doc(html_logo_url = "https://slint.dev/logo/slint-logo-square-light.svg")]
 
+mod animation_api;
+pub use animation_api::*;
+mod callback_api;
+pub use callback_api::*;
+mod clipboard_api;
+pub use clipboard_api::*;
+mod input_api;
+pub use input_api::*;
+mod item_renderer;
+mod leak_api;
+pub use leak_api::*;
+mod model_api;
+pub use model_api::*;
+mod property_api;
+pub use property_api::*;
+mod rendering_api;
+pub use rendering_api::*;
 mod search_api;
 pub use search_api::*;
 #[cfg(feature = "internal")]
@@ -13,6 +30,14 @@
 mod testing_backend;
 #[cfg(feature = "internal")]
 pub use testing_backend::*;
+mod text_api;
+pub use text_api::*;
+mod timer_api;
+pub use timer_api::*;
+mod widget_api;
+pub use widget_api::*;
+mod window_api;
+pub use window_api::*;
 #[cfg(feature = "ffi")]
 mod ffi;
 
@@ -0,0 +1,195 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use i_slint_core::model::Model;
+
+/// Returns the data of all the rows of `model`, in order.
+pub(crate) fn model_rows<M: Model + ?Sized>(model: &M) -> Vec<M::Data> {
+    (0..model.row_count()).filter_map(|row| model.row_data(row)).collect()
+}
+
+/// Panics if two consecutive rows of `model` are out of order, that is if `in_order` returns false
+/// when it is called with the data of a row and the data of the row that follows it.
+///
+/// `in_order` returns true if its first argument may come before its second one. Use it to verify
+/// the rows of a `SortModel`, or of any model that is expected to be sorted.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ModelExt, ModelRc, SharedString, VecModel};
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <[string]> names;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let names = VecModel::from_slice(&["Olivia".into(), "Liam".into(), "Emma".into()]);
+/// app.set_names(ModelRc::new(names.sort()));
+/// i_slint_backend_testing::assert_model_sorted(&app.get_names(), |a: &SharedString, b| a <= b);
+/// ```
+#[track_caller]
+pub fn assert_model_sorted<M: Model + ?Sized>(
+    model: &M,
+    in_order: impl Fn(&M::Data, &M::Data) -> bool,
+) where
+    M::Data: core::fmt::Debug,
+{
+    let rows = model_rows(model);
+    for (row, pair) in rows.windows(2).enumerate() {
+        if !in_order(&pair[0], &pair[1]) {
+            panic!(
+                "The rows {row} and {} of the model are out of order: {:?} and {:?}",
+                row + 1,
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+}
+
+/// Panics if a row of `model` doesn't match `predicate`, that is if `predicate` returns false when
+/// it is called with the data of that row.
+///
+/// Use it to verify that a `FilterModel`, or any model that is expected to be filtered, only
+/// contains the rows that it should.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ModelExt, ModelRc, VecModel};
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <[int]> even-numbers;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let numbers = VecModel::from_slice(&[1, 2, 3, 4]);
+/// app.set_even_numbers(ModelRc::new(numbers.filter(|x| x % 2 == 0)));
+/// i_slint_backend_testing::assert_model_filtered(&app.get_even_numbers(), |x| x % 2 == 0);
+/// ```
+#[track_caller]
+pub fn assert_model_filtered<M: Model + ?Sized>(model: &M, predicate: impl Fn(&M::Data) -> bool)
+where
+    M::Data: core::fmt::Debug,
+{
+    for (row, data) in model_rows(model).iter().enumerate() {
+        if !predicate(data) {
+            panic!("The row {row} of the model doesn't match the predicate: {data:?}");
+        }
+    }
+}
+
+/// Panics if `model` doesn't contain the rows of `original` in the reverse order, that is if
+/// they don't have the same number of rows, or if any row `i` of `model` isn't equal to the row
+/// `N - 1 - i` of `original`, where `N` is that number of rows.
+///
+/// Use it to verify a `ReverseModel` against its source model.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ModelExt, ModelRc, VecModel};
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <[string]> history;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let visited = VecModel::from_slice(&["home".into(), "about".into(), "contact".into()]);
+/// app.set_history(ModelRc::new(visited.clone().reverse()));
+/// i_slint_backend_testing::assert_model_reversed(&app.get_history(), &visited);
+/// ```
+#[track_caller]
+pub fn assert_model_reversed<M: Model + ?Sized, O: Model<Data = M::Data> + ?Sized>(
+    model: &M,
+    original: &O,
+) where
+    M::Data: PartialEq + core::fmt::Debug,
+{
+    let (rows, original_rows) = (model_rows(model), model_rows(original));
+    if rows.len() != original_rows.len() {
+        panic!(
+            "The model has {} rows, expected the {} rows of the original model",
+            rows.len(),
+            original_rows.len()
+        );
+    }
+    for (row, (data, expected)) in rows.iter().zip(original_rows.iter().rev()).enumerate() {
+        if data != expected {
+            panic!("The row {row} of the model is {data:?}, expected {expected:?}");
+        }
+    }
+}
+
+/// Panics if `model` doesn't have `expected_total_rows` rows, or if it doesn't provide the data
+/// of each of them.
+///
+/// Use it to verify a model that concatenates other models, and whose number of rows is the sum
+/// of theirs. A row that maps to the wrong model, or past the end of one, typically returns no
+/// data.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{Model, ModelRc, VecModel};
+/// struct Concat(Vec<ModelRc<i32>>);
+/// impl Model for Concat {
+///     type Data = i32;
+///     fn row_count(&self) -> usize {
+///         self.0.iter().map(|model| model.row_count()).sum()
+///     }
+///     fn row_data(&self, mut row: usize) -> Option<i32> {
+///         for model in &self.0 {
+///             if row < model.row_count() {
+///                 return model.row_data(row);
+///             }
+///             row -= model.row_count();
+///         }
+///         None
+///     }
+///     fn model_tracker(&self) -> &dyn slint::ModelTracker {
+///         &()
+///     }
+/// }
+/// let concat = Concat(vec![VecModel::from_slice(&[1, 2]), VecModel::from_slice(&[3])]);
+/// i_slint_backend_testing::assert_model_concatenated(&concat, 3);
+/// ```
+#[track_caller]
+pub fn assert_model_concatenated<M: Model + ?Sized>(model: &M, expected_total_rows: usize) {
+    let row_count = model.row_count();
+    if row_count != expected_total_rows {
+        panic!("The model has {row_count} rows, expected {expected_total_rows}");
+    }
+    if let Some(row) = (0..row_count).find(|row| model.row_data(*row).is_none()) {
+        panic!("The row {row} of the model has no data");
+    }
+}
+
+/// Panics if the data of the row `row` of `model` isn't `expected`, or if the model has no such
+/// row.
+///
+/// Use it to verify the result of the function of a `MapModel`, or of any model that transforms
+/// the rows of another one.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ModelExt, ModelRc, SharedString, VecModel};
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <[string]> labels;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let prices = VecModel::from_slice(&[5, 12]);
+/// app.set_labels(ModelRc::new(prices.map(|price| SharedString::from(format!("{price} €")))));
+/// i_slint_backend_testing::assert_model_mapped(&app.get_labels(), 1, "12 €".into());
+/// ```
+#[track_caller]
+pub fn assert_model_mapped<M: Model + ?Sized>(model: &M, row: usize, expected: M::Data)
+where
+    M::Data: PartialEq + core::fmt::Debug,
+{
+    let Some(data) = model.row_data(row) else {
+        panic!("The model has no row {row}, it has {} rows", model.row_count());
+    };
+    if data != expected {
+        panic!("The row {row} of the model is {data:?}, expected {expected:?}");
+    }
+}
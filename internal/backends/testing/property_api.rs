@@ -0,0 +1,177 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use i_slint_core::properties::PropertyTracker;
+use std::pin::Pin;
+
+/// Records the properties that a function reads, to verify later whether any of them changed.
+///
+/// The properties are tracked the same way as in the bindings of a `.slint` file: reading a
+/// property whose value is computed by a binding also tracks the properties that this binding
+/// reads.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::PropertyWatcher;
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <int> count;
+///         out property <string> label: "Count: " + count;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let watcher = PropertyWatcher::new(|| {
+///     app.get_label();
+/// });
+/// i_slint_backend_testing::assert_property_clean(&watcher);
+/// app.set_count(1);
+/// i_slint_backend_testing::assert_property_dirty(&watcher);
+/// ```
+pub struct PropertyWatcher(Pin<Box<PropertyTracker>>);
+
+impl PropertyWatcher {
+    /// Calls `read` and records the properties that it reads.
+    pub fn new(read: impl FnOnce()) -> Self {
+        let tracker = Box::pin(PropertyTracker::default());
+        tracker.as_ref().evaluate_as_dependency_root(read);
+        Self(tracker)
+    }
+
+    /// Returns true if any of the recorded properties changed since they were read.
+    pub fn is_dirty(&self) -> bool {
+        self.0.is_dirty()
+    }
+}
+
+/// Panics if none of the properties recorded by `watcher` changed since they were read.
+#[track_caller]
+pub fn assert_property_dirty(watcher: &PropertyWatcher) {
+    if !watcher.is_dirty() {
+        panic!("None of the watched properties changed");
+    }
+}
+
+/// Panics if any of the properties recorded by `watcher` changed since they were read.
+///
+/// Setting a property to the value that it already has doesn't change it.
+#[track_caller]
+pub fn assert_property_clean(watcher: &PropertyWatcher) {
+    if watcher.is_dirty() {
+        panic!("The watched properties changed");
+    }
+}
+
+/// Calls `set` to change some properties, then `read` to read the properties that depend on them,
+/// and returns the time that elapsed.
+///
+/// Bindings are evaluated lazily, when the properties that they compute are read, so the measured
+/// time covers the change and the new evaluation of the bindings that `read` reaches. `read` is
+/// also called once before the measurement, so that the first evaluation of these bindings isn't
+/// measured. Use it to detect regressions in the time that an update takes to propagate through
+/// the bindings.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <int> count;
+///         out property <string> label: "Count: " + count;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let duration = i_slint_backend_testing::measure_property_update_time(
+///     || app.set_count(42),
+///     || { app.get_label(); },
+/// );
+/// assert!(duration > std::time::Duration::ZERO);
+/// ```
+pub fn measure_property_update_time(set: impl FnOnce(), read: impl Fn()) -> std::time::Duration {
+    read();
+    let start = std::time::Instant::now();
+    set();
+    read();
+    start.elapsed()
+}
+
+/// Calls `set` to change some properties, then `read` to read the properties that depend on them,
+/// and returns how many bindings had to be evaluated again because of that change.
+///
+/// Bindings are evaluated lazily, when the properties that they compute are read, so the count
+/// covers the bindings that `read` reaches, including the ones of the properties that these
+/// bindings read in turn. `read` is also called once before, so that the first evaluation of these
+/// bindings isn't counted. Use it to verify that a change doesn't evaluate more bindings than
+/// needed.
+///
+/// This requires the `debug-counters` feature of this crate.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <int> count;
+///         in property <int> other;
+///         out property <string> label: "Count: " + count;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let evaluations = i_slint_backend_testing::count_property_evaluations(
+///     || app.set_count(42),
+///     || { app.get_label(); },
+/// );
+/// assert_eq!(evaluations, 1);
+/// let evaluations = i_slint_backend_testing::count_property_evaluations(
+///     || app.set_other(42),
+///     || { app.get_label(); },
+/// );
+/// assert_eq!(evaluations, 0);
+/// ```
+#[cfg(feature = "debug-counters")]
+pub fn count_property_evaluations(set: impl FnOnce(), read: impl Fn()) -> u32 {
+    read();
+    let start = i_slint_core::properties::binding_evaluation_count();
+    set();
+    read();
+    i_slint_core::properties::binding_evaluation_count().wrapping_sub(start)
+}
+
+/// Calls `read` to read some properties, and panics if the evaluation of their bindings ended up
+/// reading a property whose binding was still being evaluated, which means that the binding of
+/// that property depends on itself.
+///
+/// The compiler reports the binding loops that it can see in the `.slint` files. A loop that goes
+/// through native code, such as the handler of a callback that reads the property whose binding
+/// invokes it, is detected when the property is read recursively, instead of looping forever.
+/// `name` is only used in the panic message. Other panics that happen in `read` are propagated.
+///
+/// The recursion is detected from the panic that it raises, so there is no C++ version of this
+/// function: a panic can't unwind through the C++ code of the callbacks and bindings.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <int> count;
+///         pure callback format(int) -> string;
+///         out property <string> label: format(count);
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// app.on_format(|count| slint::format!("Count: {count}"));
+/// i_slint_backend_testing::assert_no_binding_cycle("label", || {
+///     app.get_label();
+/// });
+/// ```
+#[track_caller]
+pub fn assert_no_binding_cycle(name: &str, read: impl FnOnce()) {
+    let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(read)) else {
+        return;
+    };
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+    if message == Some(i_slint_core::properties::RECURSION_DETECTED_MESSAGE) {
+        panic!("The property {name} is part of a binding loop");
+    }
+    std::panic::resume_unwind(payload);
+}
@@ -0,0 +1,694 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::testing_backend::with_testing_window;
+use crate::ElementHandle;
+use i_slint_core::api::{ComponentHandle, LogicalPosition, LogicalSize};
+use i_slint_core::graphics::{Brush, Color};
+use i_slint_core::item_rendering::RenderBorderRectangle;
+use i_slint_core::item_tree::{visit_items, ItemVisitorResult, TraversalOrder};
+use i_slint_core::items::{
+    BasicBorderRectangle, BorderRectangle, BoxShadow, Clip, Flickable, ItemRc, Layer, Opacity,
+    Path, Rectangle, Rotate, Text, TextInput, WindowItem,
+};
+use i_slint_core::lengths::{
+    logical_position_to_api, logical_size_to_api, LogicalBorderRadius, LogicalRect,
+};
+use i_slint_core::window::WindowInner;
+
+/// Runs `body` and returns the number of frames that were rendered for the window of `component`
+/// while doing so.
+///
+/// The testing backend doesn't produce any pixels, but it visits the elements of a window like a real
+/// renderer when a redraw was requested, for example because a property that affects the appearance
+/// of an element changed. A redraw that is still pending after `body` returns is rendered and counted.
+/// A redraw that was already pending before `body` is called is rendered beforehand and not counted.
+///
+/// Returns 0 if the window of `component` was not created by the testing backend.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <color> color: red;
+///         in property <int> counter;
+///         Rectangle { background: color; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let blue = slint::Color::from_rgb_u8(0, 0, 255);
+/// assert_eq!(i_slint_backend_testing::with_render_counter(&app, || app.set_color(blue)), 1);
+/// // The counter doesn't affect the rendering
+/// assert_eq!(i_slint_backend_testing::with_render_counter(&app, || app.set_counter(42)), 0);
+/// ```
+pub fn with_render_counter(component: &impl ComponentHandle, body: impl FnOnce()) -> u32 {
+    let window = component.window();
+    let Some(start) = with_testing_window(window, |w| {
+        w.draw_if_needed();
+        w.rendered_frames()
+    }) else {
+        return 0;
+    };
+    body();
+    with_testing_window(window, |w| {
+        w.draw_if_needed();
+        w.rendered_frames() - start
+    })
+    .unwrap_or_default()
+}
+
+/// Runs `body` and panics if more than `max_passes` frames were rendered for the window of
+/// `component` while doing so.
+///
+/// See [`with_render_counter`] for how the frames are counted. Pass 0 to verify that a change
+/// doesn't affect the rendering at all.
+#[track_caller]
+pub fn assert_render_passes_count(
+    component: &impl ComponentHandle,
+    max_passes: u32,
+    body: impl FnOnce(),
+) {
+    let passes = with_render_counter(component, body);
+    if passes > max_passes {
+        panic!("The change caused {passes} render passes, expected at most {max_passes}");
+    }
+}
+
+/// Calls `create` to construct a component, renders the first frame of its window, and returns the
+/// component together with the time that elapsed from the start of the construction until that
+/// frame was rendered.
+///
+/// The testing backend doesn't produce any pixels, so the measured time covers the instantiation
+/// of the component, the first evaluation of the bindings and the layouts, and the traversal of
+/// the elements by the renderer. Use it to detect regressions of the startup time.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle { background: red; }
+///     }
+/// }
+/// let (app, duration) = i_slint_backend_testing::measure_first_frame_time(|| App::new().unwrap());
+/// assert!(duration > std::time::Duration::ZERO);
+/// # drop(app);
+/// ```
+pub fn measure_first_frame_time<C: ComponentHandle>(
+    create: impl FnOnce() -> C,
+) -> (C, std::time::Duration) {
+    let start = std::time::Instant::now();
+    let component = create();
+    let window = component.window();
+    window.request_redraw();
+    with_testing_window(window, |w| w.draw_if_needed());
+    (component, start.elapsed())
+}
+
+/// Renders the window of `component` if a redraw is pending, and panics if `element` wasn't last
+/// painted at `position` with `size`, in logical window coordinates.
+///
+/// Only elements that draw something, such as `Rectangle`, `Image`, `Text` or `Path`, are
+/// painted. Use this to check where an element ends up on the screen, for example after an
+/// animation or a change of the layout.
+#[track_caller]
+pub fn assert_painting_rect(
+    component: &impl ComponentHandle,
+    element: &ElementHandle,
+    position: LogicalPosition,
+    size: LogicalSize,
+) {
+    let rect = with_testing_window(component.window(), |w| {
+        w.draw_if_needed();
+        element.item_rc().and_then(|item| w.painting_rect(&item.downgrade()))
+    })
+    .flatten();
+    let Some(rect) = rect else {
+        panic!("The element was not painted in the window of the component");
+    };
+    let (painted_position, painted_size) =
+        (logical_position_to_api(rect.origin), logical_size_to_api(rect.size));
+    if (painted_position, painted_size) != (position, size) {
+        panic!(
+            "The element was painted at {painted_position:?} with size {painted_size:?}, expected {position:?} with size {size:?}"
+        );
+    }
+}
+
+/// Renders the window of `component` if a redraw is pending, and panics if the region that this
+/// frame repainted doesn't have the bounds `position` and `size`, in logical window coordinates.
+///
+/// Only the parts of the window whose appearance changed since the previous frame are repainted,
+/// such as the old and the new geometry of an element that moved. The region is empty when no
+/// redraw was pending. Use this to verify that a change doesn't cause more of the window to be
+/// repainted than necessary.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{ComponentHandle, LogicalPosition, LogicalSize};
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 100px;
+///         height: 100px;
+///         in property <color> color: red;
+///         Rectangle { x: 10px; y: 20px; width: 30px; height: 40px; background: color; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// // The first frame repaints the whole window
+/// app.window().request_redraw();
+/// i_slint_backend_testing::assert_repaint_region(&app, LogicalPosition::new(0., 0.), LogicalSize::new(100., 100.));
+/// app.set_color(slint::Color::from_rgb_u8(0, 0, 255));
+/// i_slint_backend_testing::assert_repaint_region(&app, LogicalPosition::new(10., 20.), LogicalSize::new(30., 40.));
+/// ```
+#[track_caller]
+pub fn assert_repaint_region(
+    component: &impl ComponentHandle,
+    position: LogicalPosition,
+    size: LogicalSize,
+) {
+    let Some(rect) = with_testing_window(component.window(), |w| {
+        if w.draw_if_needed() {
+            w.repaint_region()
+        } else {
+            Default::default()
+        }
+    }) else {
+        panic!("The window of the component wasn't created by the testing backend");
+    };
+    let (repainted_position, repainted_size) =
+        (logical_position_to_api(rect.origin), logical_size_to_api(rect.size));
+    if (repainted_position, repainted_size) != (position, size) {
+        panic!(
+            "The repainted region is at {repainted_position:?} with size {repainted_size:?}, expected {position:?} with size {size:?}"
+        );
+    }
+}
+
+/// Returns true if the children of `item` are clipped to its geometry.
+fn is_clipping(item: &ItemRc) -> bool {
+    item.downcast::<Flickable>().is_some()
+        || item.downcast::<Clip>().map_or(false, |clip| clip.as_pin_ref().clip())
+}
+
+/// Returns the rectangle, in window coordinates, to which the children of `item` are clipped by
+/// `item` and its ancestors, or None if they aren't clipped.
+pub(crate) fn clipping_rect(item: &ItemRc) -> Option<LogicalRect> {
+    // The `clip` property of a Rectangle is implemented with a `Clip` child that has no debug info
+    let clip_child = std::iter::successors(item.first_child(), |child| child.next_sibling())
+        .find(|child| child.downcast::<Clip>().is_some() && child.element_infos().is_none());
+    std::iter::successors(clip_child.or_else(|| Some(item.clone())), |item| item.parent_item())
+        .filter(is_clipping)
+        .map(|item| {
+            let geometry = item.geometry();
+            LogicalRect::new(item.map_to_window(geometry.origin), geometry.size)
+        })
+        .reduce(|clip, rect| clip.intersection(&rect).unwrap_or_default())
+}
+
+/// Panics if the children of `element` aren't clipped to the rectangle at `position` with `size`,
+/// in logical window coordinates.
+///
+/// The children are clipped by the element if it is a `Rectangle` with `clip: true`, or a
+/// `Flickable`, and by all the ancestors of the element that clip their children. The rectangle
+/// is the intersection of the geometries of all these elements.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::{LogicalPosition, LogicalSize};
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             x: 10px;
+///             y: 20px;
+///             width: 30px;
+///             height: 40px;
+///             clip: true;
+///             accessible-role: list;
+///             accessible-label: "Thumbnails";
+///             Rectangle { width: 100px; height: 100px; background: red; }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let thumbnails = ElementHandle::find_by_accessible_label(&app, "Thumbnails").next().unwrap();
+/// i_slint_backend_testing::assert_clipping_rect(&thumbnails, LogicalPosition::new(10., 20.), LogicalSize::new(30., 40.));
+/// ```
+#[track_caller]
+pub fn assert_clipping_rect(element: &ElementHandle, position: LogicalPosition, size: LogicalSize) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let Some(rect) = clipping_rect(&item) else {
+        panic!("The children of the element aren't clipped");
+    };
+    let (clip_position, clip_size) =
+        (logical_position_to_api(rect.origin), logical_size_to_api(rect.size));
+    if (clip_position, clip_size) != (position, size) {
+        panic!(
+            "The children of the element are clipped at {clip_position:?} with size {clip_size:?}, expected {position:?} with size {size:?}"
+        );
+    }
+}
+
+/// Returns the element of type `T` that the compiler injected as a parent of `item` to implement
+/// a property such as `opacity` or `rotation-angle`, if any.
+fn injected_parent<T: vtable::HasStaticVTable<i_slint_core::items::ItemVTable>>(
+    item: &ItemRc,
+) -> Option<vtable::VRcMapped<i_slint_core::item_tree::ItemTreeVTable, T>> {
+    std::iter::successors(item.parent_item(), |parent| parent.parent_item())
+        .take_while(|parent| {
+            parent.element_infos().is_none()
+                && (parent.downcast::<Opacity>().is_some()
+                    || parent.downcast::<Layer>().is_some()
+                    || parent.downcast::<Clip>().is_some()
+                    || parent.downcast::<Rotate>().is_some()
+                    || parent.downcast::<BoxShadow>().is_some())
+        })
+        .find_map(|parent| parent.downcast::<T>())
+}
+
+/// Returns the value of the `opacity` property of `item`.
+pub(crate) fn opacity(item: &ItemRc) -> f32 {
+    injected_parent::<Opacity>(item).map_or(1., |opacity| opacity.as_pin_ref().opacity())
+}
+
+/// Returns the value of the `rotation-angle` property of `item`, in degrees.
+pub(crate) fn rotation_angle(item: &ItemRc) -> f32 {
+    injected_parent::<Rotate>(item).map_or(0., |rotate| rotate.as_pin_ref().rotation_angle())
+}
+
+/// Panics if the `opacity` property of `element` doesn't match `expected`, within 0.001.
+///
+/// This is the opacity of the element itself, not multiplied with the opacity of its ancestors.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             opacity: 0.5;
+///             accessible-role: text;
+///             accessible-label: "Hint";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let hint = ElementHandle::find_by_accessible_label(&app, "Hint").next().unwrap();
+/// i_slint_backend_testing::assert_opacity(&hint, 0.5);
+/// ```
+#[track_caller]
+pub fn assert_opacity(element: &ElementHandle, expected: f32) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let actual = opacity(&item);
+    if (actual - expected).abs() > 0.001 {
+        panic!("The opacity of the element is {actual}, expected {expected}");
+    }
+}
+
+/// Panics if the `rotation-angle` property of `element` doesn't match `expected_degrees`, within
+/// 0.01 degrees.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Image {
+///             rotation-angle: 0.25turn;
+///             accessible-label: "Compass";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let compass = ElementHandle::find_by_accessible_label(&app, "Compass").next().unwrap();
+/// i_slint_backend_testing::assert_rotation_angle(&compass, 90.);
+/// ```
+#[track_caller]
+pub fn assert_rotation_angle(element: &ElementHandle, expected_degrees: f32) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let actual = rotation_angle(&item);
+    if (actual - expected_degrees).abs() > 0.01 {
+        panic!("The element is rotated by {actual} degrees, expected {expected_degrees} degrees");
+    }
+}
+
+/// Returns the blur, the horizontal and vertical offsets, and the color of the drop shadow of
+/// `item`. The shadow is drawn by an element that the compiler inserted before `item`, or as its
+/// parent when `item` is repeated.
+pub(crate) fn drop_shadow(item: &ItemRc) -> (f32, f32, f32, Color) {
+    item.previous_sibling()
+        .filter(|sibling| sibling.element_infos().is_none())
+        .and_then(|sibling| sibling.downcast::<BoxShadow>())
+        .or_else(|| injected_parent::<BoxShadow>(item))
+        .map_or((0., 0., 0., Color::default()), |shadow| {
+            let shadow = shadow.as_pin_ref();
+            (shadow.blur().get(), shadow.offset_x().get(), shadow.offset_y().get(), shadow.color())
+        })
+}
+
+/// Panics if the `drop-shadow-blur`, `drop-shadow-offset-x`, `drop-shadow-offset-y`, and
+/// `drop-shadow-color` properties of `element` don't match `blur`, `offset_x`, `offset_y`, and
+/// `color`. The lengths are in logical pixels and are compared within 0.01.
+///
+/// An element without drop shadow has no blur, no offset, and a transparent shadow color.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             width: 100px;
+///             height: 50px;
+///             drop-shadow-blur: 4px;
+///             drop-shadow-offset-y: 2px;
+///             drop-shadow-color: #0008;
+///             accessible-role: text;
+///             accessible-label: "Card";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let card = ElementHandle::find_by_accessible_label(&app, "Card").next().unwrap();
+/// let color = slint::Color::from_argb_u8(0x88, 0, 0, 0);
+/// i_slint_backend_testing::assert_drop_shadow(&card, 4., 0., 2., color);
+/// ```
+#[track_caller]
+pub fn assert_drop_shadow(
+    element: &ElementHandle,
+    blur: f32,
+    offset_x: f32,
+    offset_y: f32,
+    color: Color,
+) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let (actual_blur, actual_offset_x, actual_offset_y, actual_color) = drop_shadow(&item);
+    if (actual_blur - blur).abs() > 0.01
+        || (actual_offset_x - offset_x).abs() > 0.01
+        || (actual_offset_y - offset_y).abs() > 0.01
+        || actual_color != color
+    {
+        panic!(
+            "The drop shadow of the element has blur {actual_blur}, offset ({actual_offset_x}, {actual_offset_y}) and color {actual_color:?}, expected blur {blur}, offset ({offset_x}, {offset_y}) and color {color:?}"
+        );
+    }
+}
+
+/// Returns the radii of the corners of `item`, or None if it isn't a `Rectangle`.
+pub(crate) fn border_radius(item: &ItemRc) -> Option<LogicalBorderRadius> {
+    if let Some(rect) = item.downcast::<BorderRectangle>() {
+        Some(RenderBorderRectangle::border_radius(rect.as_pin_ref()))
+    } else if let Some(rect) = item.downcast::<BasicBorderRectangle>() {
+        Some(RenderBorderRectangle::border_radius(rect.as_pin_ref()))
+    } else {
+        item.downcast::<Rectangle>().map(|_| LogicalBorderRadius::new_uniform(0.))
+    }
+}
+
+/// Panics if `element` isn't a `Rectangle` whose four corners have the radius `expected`, in
+/// logical pixels, within 0.01.
+///
+/// The radius of a corner is the one of its `border-*-radius` property if set, and the one of
+/// `border-radius` otherwise.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             border-radius: 8px;
+///             background: blue;
+///             accessible-role: button;
+///             accessible-label: "Chip";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let chip = ElementHandle::find_by_accessible_label(&app, "Chip").next().unwrap();
+/// i_slint_backend_testing::assert_border_radius(&chip, 8.);
+/// ```
+#[track_caller]
+pub fn assert_border_radius(element: &ElementHandle, expected: f32) {
+    assert_border_radii(element, expected, expected, expected, expected);
+}
+
+/// Panics if `element` isn't a `Rectangle` whose top-left, top-right, bottom-right, and
+/// bottom-left corners have the radii `top_left`, `top_right`, `bottom_right`, and `bottom_left`,
+/// in logical pixels, within 0.01.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             border-radius: 8px;
+///             border-bottom-left-radius: 0px;
+///             border-bottom-right-radius: 0px;
+///             background: blue;
+///             accessible-role: tab;
+///             accessible-label: "Tab";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let tab = ElementHandle::find_by_accessible_label(&app, "Tab").next().unwrap();
+/// i_slint_backend_testing::assert_border_radii(&tab, 8., 8., 0., 0.);
+/// ```
+#[track_caller]
+pub fn assert_border_radii(
+    element: &ElementHandle,
+    top_left: f32,
+    top_right: f32,
+    bottom_right: f32,
+    bottom_left: f32,
+) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let Some(radius) = border_radius(&item) else {
+        panic!("The element isn't a Rectangle");
+    };
+    let actual = [radius.top_left, radius.top_right, radius.bottom_right, radius.bottom_left];
+    let expected = [top_left, top_right, bottom_right, bottom_left];
+    if actual.iter().zip(expected.iter()).any(|(actual, expected)| (actual - expected).abs() > 0.01)
+    {
+        panic!(
+            "The corners of the element have the radii {actual:?}, expected {expected:?} (top-left, top-right, bottom-right, bottom-left)"
+        );
+    }
+}
+
+/// Returns the `stroke-width` property of `item`, in logical pixels, or None if it isn't a `Path`.
+pub(crate) fn stroke_width(item: &ItemRc) -> Option<f32> {
+    Some(item.downcast::<Path>()?.as_pin_ref().stroke_width().get())
+}
+
+/// Panics if `element` isn't a `Path` whose `stroke-width` property matches `expected`, in
+/// logical pixels, within 0.01.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Path {
+///             commands: "M 0 0 L 100 100";
+///             stroke: black;
+///             stroke-width: 2px;
+///             accessible-role: image;
+///             accessible-label: "Arrow";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let arrow = ElementHandle::find_by_accessible_label(&app, "Arrow").next().unwrap();
+/// i_slint_backend_testing::assert_stroke_width(&arrow, 2.);
+/// ```
+#[track_caller]
+pub fn assert_stroke_width(element: &ElementHandle, expected: f32) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let Some(width) = stroke_width(&item) else {
+        panic!("The element isn't a Path");
+    };
+    if (width - expected).abs() > 0.01 {
+        panic!("The stroke width of the path is {width}px, expected {expected}px");
+    }
+}
+
+/// Returns the value of the brush property named `property` of `item`, such as `background` or
+/// `stroke`, or None if the item has no brush property with that name.
+pub(crate) fn brush_property(item: &ItemRc, property: &str) -> Option<Brush> {
+    if let Some(rect) = item.downcast::<Rectangle>() {
+        let rect = rect.as_pin_ref();
+        (property == "background").then(|| rect.background())
+    } else if let Some(rect) = item.downcast::<BasicBorderRectangle>() {
+        let rect = rect.as_pin_ref();
+        match property {
+            "background" => Some(rect.background()),
+            "border-color" => Some(rect.border_color()),
+            _ => None,
+        }
+    } else if let Some(rect) = item.downcast::<BorderRectangle>() {
+        let rect = rect.as_pin_ref();
+        match property {
+            "background" => Some(rect.background()),
+            "border-color" => Some(rect.border_color()),
+            _ => None,
+        }
+    } else if let Some(path) = item.downcast::<Path>() {
+        let path = path.as_pin_ref();
+        match property {
+            "fill" => Some(path.fill()),
+            "stroke" => Some(path.stroke()),
+            _ => None,
+        }
+    } else if let Some(text) = item.downcast::<Text>() {
+        let text = text.as_pin_ref();
+        match property {
+            "color" => Some(text.color()),
+            "stroke" => Some(text.stroke()),
+            _ => None,
+        }
+    } else if let Some(text_input) = item.downcast::<TextInput>() {
+        (property == "color").then(|| text_input.as_pin_ref().color())
+    } else if let Some(window) = item.downcast::<WindowItem>() {
+        (property == "background").then(|| window.as_pin_ref().background())
+    } else {
+        None
+    }
+}
+
+/// Panics if the brush property named `property` of `element`, such as `background` or `fill`,
+/// isn't a linear gradient with the color stops `expected`. Each stop is a color and a position
+/// between 0 and 1, compared within 0.001. The stops are sorted by position.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// use slint::Color;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             background: @linear-gradient(90deg, #ff0000 0%, #0000ff 100%);
+///             accessible-role: image;
+///             accessible-label: "Banner";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let banner = ElementHandle::find_by_accessible_label(&app, "Banner").next().unwrap();
+/// i_slint_backend_testing::assert_linear_gradient_stops(
+///     &banner,
+///     "background",
+///     &[(Color::from_rgb_u8(255, 0, 0), 0.), (Color::from_rgb_u8(0, 0, 255), 1.)],
+/// );
+/// ```
+#[track_caller]
+pub fn assert_linear_gradient_stops(
+    element: &ElementHandle,
+    property: &str,
+    expected: &[(Color, f32)],
+) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let gradient = match brush_property(&item, property) {
+        Some(Brush::LinearGradient(gradient)) => gradient,
+        Some(brush) => panic!("The {property} of the element isn't a linear gradient: {brush:?}"),
+        None => panic!("The element has no brush property named {property:?}"),
+    };
+    let stops: Vec<_> = gradient.stops().map(|stop| (stop.color, stop.position)).collect();
+    if stops.len() != expected.len()
+        || stops.iter().zip(expected.iter()).any(
+            |((color, position), (expected_color, expected_position))| {
+                color != expected_color || (position - expected_position).abs() > 0.001
+            },
+        )
+    {
+        panic!(
+            "The {property} of the element has the gradient stops {stops:?}, expected {expected:?}"
+        );
+    }
+}
+
+/// Returns true if `item` is likely to be rendered into a separate layer by a GPU renderer.
+///
+/// This is an approximation: the renderers decide this on their own while rendering, and don't
+/// expose that decision. The elements counted here are those with an `opacity` below 1 that
+/// have several children or grandchildren (using the same [`Opacity::need_layer`] check as the
+/// renderers), those with `cache-rendering-hint: true`, and those that clip their children with
+/// a border radius.
+pub(crate) fn likely_needs_compositing_layer(item: &ItemRc) -> bool {
+    if let Some(opacity) = item.downcast::<Opacity>() {
+        Opacity::need_layer(item, opacity.as_pin_ref().opacity())
+    } else if let Some(layer) = item.downcast::<Layer>() {
+        layer.as_pin_ref().cache_rendering_hint()
+    } else if let Some(clip) = item.downcast::<Clip>() {
+        let clip = clip.as_pin_ref();
+        clip.clip() && !clip.logical_border_radius().is_zero()
+    } else {
+        false
+    }
+}
+
+/// Returns an estimate of the number of layers that a GPU renderer composes to render the window
+/// of `component`.
+///
+/// The testing backend doesn't use the GPU, and the renderers don't report the layers they
+/// create, so this counts the elements of the window that are likely to need a separate layer:
+/// elements with an `opacity` below 1 that have several children or grandchildren, elements with
+/// `cache-rendering-hint: true`, and elements that clip their children with a border radius.
+/// A renderer may create fewer layers, for example when it can draw such an element directly.
+/// Each layer costs memory and a compositing step in every frame, so use this to verify that a
+/// user interface doesn't grow more candidates for layers than expected.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle {
+///             opacity: 0.5;
+///             Text { text: "Faded"; }
+///         }
+///         Rectangle {
+///             border-radius: 5px;
+///             clip: true;
+///             Text { text: "Rounded"; }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// assert_eq!(i_slint_backend_testing::count_compositing_layers(&app), 2);
+/// ```
+pub fn count_compositing_layers(component: &impl ComponentHandle) -> u32 {
+    compositing_layers(component.window())
+}
+
+pub(crate) fn compositing_layers(window: &i_slint_core::api::Window) -> u32 {
+    let Some(item_tree) = WindowInner::from_pub(window).try_component() else { return 0 };
+    let mut count = 0;
+    visit_items(
+        &item_tree,
+        TraversalOrder::BackToFront,
+        |item_tree, _, index, _| {
+            if likely_needs_compositing_layer(&ItemRc::new(item_tree.clone(), index)) {
+                count += 1;
+            }
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+    count
+}
@@ -4,9 +4,12 @@
 use i_slint_core::accessibility::{AccessibilityAction, AccessibleStringProperty};
 use i_slint_core::item_tree::{ItemTreeRc, ItemVisitorResult, ItemWeak, TraversalOrder};
 use i_slint_core::items::ItemRc;
+use i_slint_core::lengths::{LogicalPoint, LogicalRect};
 use i_slint_core::window::WindowInner;
 use i_slint_core::{SharedString, SharedVector};
 
+pub use i_slint_core::items::AccessibleRole;
+
 pub(crate) fn search_item(
     item_tree: &ItemTreeRc,
     mut filter: impl FnMut(&ItemRc) -> bool,
@@ -27,16 +30,59 @@ pub(crate) fn search_item(
     result
 }
 
+/// Returns the `(type name, qualified id)` of each element that makes up `item`, starting with
+/// the outermost one. The id is None for elements that don't have one.
+fn element_infos(item: &ItemRc) -> Vec<(String, Option<String>)> {
+    let Some(infos) = item.element_infos() else { return Vec::new() };
+    infos
+        .lines()
+        .map(|line| match line.split_once(';') {
+            Some((type_name, id)) => (type_name.to_string(), Some(id.to_string())),
+            None => (line.to_string(), None),
+        })
+        .collect()
+}
+
+pub(crate) fn item_has_element_id(item: &ItemRc, id: &str) -> bool {
+    let id = id.replace('_', "-");
+    element_infos(item).iter().any(|(_, element_id)| element_id.as_deref() == Some(id.as_str()))
+}
+
+/// Returns the first descendant of `item`, in depth-first order, for which `filter` returns true.
+pub(crate) fn find_descendant(item: &ItemRc, filter: &impl Fn(&ItemRc) -> bool) -> Option<ItemRc> {
+    let mut child = item.first_child();
+    while let Some(item) = child {
+        if filter(&item) {
+            return Some(item);
+        }
+        if let Some(found) = find_descendant(&item, filter) {
+            return Some(found);
+        }
+        child = item.next_sibling();
+    }
+    None
+}
+
 /// `ElementHandle`` wraps an existing element in a Slint UI. An ElementHandle does not keep
 /// the corresponding element in the UI alive. Use [`Self::is_valid()`] to verify that
 /// it is still alive.
 ///
 /// Obtain instances of `ElementHandle` by querying your application through
-/// [`Self::find_by_accessible_label()`].
+/// [`Self::find_by_accessible_label()`] or [`Self::find_by_element_id()`].
 #[derive(Clone)]
 pub struct ElementHandle(ItemWeak);
 
 impl ElementHandle {
+    /// Returns a handle to the element of `item`.
+    pub(crate) fn new(item: ItemWeak) -> Self {
+        Self(item)
+    }
+
+    /// Returns the item of the element, or None if the element doesn't exist anymore.
+    pub(crate) fn item_rc(&self) -> Option<ItemRc> {
+        self.0.upgrade()
+    }
+
     /// Returns true if the element still exists in the in UI and is valid to access; false otherwise.
     pub fn is_valid(&self) -> bool {
         self.0.upgrade().is_some()
@@ -58,6 +104,65 @@ pub fn find_by_accessible_label(
         result.into_iter().map(|x| ElementHandle(x))
     }
 
+    /// This function searches through the entire tree of elements of `component`, looks for
+    /// elements that were declared with the given id, and returns an iterator over the found elements.
+    ///
+    /// The id must be qualified with the name of the component it was declared in, for example
+    /// `MyComponent::my-button`. Several elements are found if the component is instantiated several
+    /// times. The root element of a component has the id `MyComponent::root`.
+    ///
+    /// This requires the debug information to be generated by the Slint compiler, which is
+    /// enabled by setting the `SLINT_EMIT_DEBUG_INFO` environment variable when compiling.
+    pub fn find_by_element_id(
+        component: &impl i_slint_core::api::ComponentHandle,
+        id: &str,
+    ) -> impl Iterator<Item = Self> {
+        let item_tree = WindowInner::from_pub(component.window()).component();
+        let result = search_item(&item_tree, |item| item_has_element_id(item, id));
+        result.into_iter().map(|x| ElementHandle(x))
+    }
+
+    /// Returns the id of the element as declared in the Slint code, qualified with the name of
+    /// the component, for example `MyComponent::my-button`.
+    /// Returns None if the element has no id or if no debug information was generated.
+    pub fn id(&self) -> Option<SharedString> {
+        self.0
+            .upgrade()
+            .and_then(|item| element_infos(&item).into_iter().find_map(|(_, id)| id))
+            .map(SharedString::from)
+    }
+
+    /// Returns the name of the type of the element as written in the Slint code, for example
+    /// `Rectangle` or `MyButton`. Returns None if no debug information was generated.
+    pub fn type_name(&self) -> Option<SharedString> {
+        self.0
+            .upgrade()
+            .and_then(|item| element_infos(&item).into_iter().next())
+            .map(|(type_name, _)| type_name.into())
+    }
+
+    /// Returns the properties that are read by the binding of `property` of this element, as
+    /// `MyComponent::my-button.text`, or as `TypeName.property` for elements without id.
+    /// Returns None if the property has no binding or if no debug information was generated.
+    pub fn property_dependencies(&self, property: &str) -> Option<Vec<SharedString>> {
+        self.0
+            .upgrade()
+            .and_then(|item| item.property_dependencies(property))
+            .map(|dependencies| dependencies.into_iter().collect())
+    }
+
+    /// Returns the name of the state of this element that currently applies, as declared in its
+    /// `states` block, or an empty string if no state applies.
+    /// Returns None if the element has no states or if no debug information was generated.
+    pub fn current_state(&self) -> Option<SharedString> {
+        self.0.upgrade().and_then(|item| item.current_state())
+    }
+
+    /// Returns the value of the element's `accessible-role` property, if the element is accessible.
+    pub fn accessible_role(&self) -> Option<AccessibleRole> {
+        self.0.upgrade().filter(|item| item.is_accessible()).map(|item| item.accessible_role())
+    }
+
     /// Invokes the default accessible action on the element. For example a `MyButton` element might declare
     /// an accessible default action that simulates a click, as in the following example:
     ///
@@ -110,6 +215,26 @@ pub fn accessible_description(&self) -> Option<SharedString> {
             .and_then(|item| item.accessible_string_property(AccessibleStringProperty::Description))
     }
 
+    /// Returns the value of the `accessible-value-minimum` property, if present.
+    pub fn accessible_value_minimum(&self) -> Option<f32> {
+        self.0
+            .upgrade()
+            .and_then(|item| {
+                item.accessible_string_property(AccessibleStringProperty::ValueMinimum)
+            })
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Returns the value of the `accessible-value-maximum` property, if present.
+    pub fn accessible_value_maximum(&self) -> Option<f32> {
+        self.0
+            .upgrade()
+            .and_then(|item| {
+                item.accessible_string_property(AccessibleStringProperty::ValueMaximum)
+            })
+            .and_then(|value| value.parse().ok())
+    }
+
     /// Returns the value of the `accessible-checked` property, if present
     pub fn accessible_checked(&self) -> Option<bool> {
         self.0
@@ -143,3 +268,216 @@ pub fn absolute_position(&self) -> i_slint_core::api::LogicalPosition {
             .unwrap_or_default()
     }
 }
+
+/// Panics if the number of instances of the repeated element declared with the id `element_id`
+/// in `component` isn't `expected`.
+///
+/// The element is the one that is repeated with a `for` (or instantiated with an `if`), and its
+/// id is qualified with the name of the component as in [`ElementHandle::find_by_element_id()`].
+/// The instances are counted by visiting the element tree of `component`, and visiting a
+/// repeater updates it first, so changes to its model since the last frame are taken into
+/// account without rendering.
+#[track_caller]
+pub fn assert_repeater_count(
+    component: &impl i_slint_core::api::ComponentHandle,
+    element_id: &str,
+    expected: usize,
+) {
+    let count = ElementHandle::find_by_element_id(component, element_id).count();
+    if count != expected {
+        panic!("Expected {expected} instance(s) of {element_id}, found {count}");
+    }
+}
+
+/// Writes a line for `item` and each of its children to `out`, indented by `depth`.
+pub(crate) fn dump_item(item: &ItemRc, depth: usize, out: &mut String) {
+    use std::fmt::Write;
+    let infos = element_infos(item);
+    let type_name = infos.first().map_or("Item", |(type_name, _)| type_name.as_str());
+    let geometry = item.geometry();
+    let _ = write!(out, "{:indent$}{type_name}", "", indent = depth * 2);
+    for id in infos.iter().filter_map(|(_, id)| id.as_deref()) {
+        let _ = write!(out, " {id}");
+    }
+    let _ = writeln!(
+        out,
+        " ({}, {}) {}x{}",
+        geometry.origin.x, geometry.origin.y, geometry.size.width, geometry.size.height
+    );
+    let mut child = item.first_child();
+    while let Some(item) = child {
+        dump_item(&item, depth + 1, out);
+        child = item.next_sibling();
+    }
+}
+
+/// Returns a text representation of the tree of elements of `component`, to help diagnosing the
+/// failure of a test.
+///
+/// Each element is on its own line, indented according to its depth in the tree, with the name
+/// of its type, its qualified ids as in [`ElementHandle::find_by_element_id()`], and its position
+/// relative to its parent and its size, in logical pixels. The elements that were inlined into
+/// their parent, such as the root of a sub-component, are shown on the same line as that parent.
+///
+/// The types and ids require the debug information to be generated by the Slint compiler.
+/// Without it, every element is shown as `Item`.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         width: 100px;
+///         height: 100px;
+///         Rectangle { background: red; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// eprintln!("{}", i_slint_backend_testing::dump_item_tree(&app));
+/// ```
+pub fn dump_item_tree(component: &impl i_slint_core::api::ComponentHandle) -> SharedString {
+    let item_tree = WindowInner::from_pub(component.window()).component();
+    let mut result = String::new();
+    dump_item(&ItemRc::new(item_tree, 0), 0, &mut result);
+    result.into()
+}
+
+/// Returns the number of levels of the tree of elements that starts at `item`, including `item`.
+pub(crate) fn item_tree_depth(item: &ItemRc) -> u32 {
+    let mut depth = 0;
+    let mut child = item.first_child();
+    while let Some(item) = child {
+        depth = depth.max(item_tree_depth(&item));
+        child = item.next_sibling();
+    }
+    depth + 1
+}
+
+/// Panics if the tree of elements that starts at `element` doesn't have `expected` levels.
+///
+/// An element without children has one level. The elements that were inlined into their parent,
+/// such as the root of a sub-component, don't add a level, as in [`dump_item_tree()`]. Use it
+/// to verify the nesting of elements that are created from a model, such as the rows of a tree
+/// that indent their children with nested repeaters.
+#[track_caller]
+pub fn assert_item_tree_depth(element: &ElementHandle, expected: u32) {
+    let Some(item) = element.item_rc() else {
+        panic!("The element doesn't exist anymore");
+    };
+    let depth = item_tree_depth(&item);
+    if depth != expected {
+        panic!("The tree of elements has {depth} level(s), expected {expected}");
+    }
+}
+
+/// Returns the qualified id of the topmost visible element with an id that covers `position`,
+/// in logical window coordinates.
+pub(crate) fn item_tree_id_at_position(
+    item_tree: &ItemTreeRc,
+    position: LogicalPoint,
+) -> Option<SharedString> {
+    let mut result = None;
+    // Visiting from back to front, the last element that covers the position is the topmost one
+    i_slint_core::item_tree::visit_items(
+        item_tree,
+        TraversalOrder::BackToFront,
+        |parent_tree, _, index, _| {
+            let item = ItemRc::new(parent_tree.clone(), index);
+            let geometry = item.geometry();
+            let rect = LogicalRect::new(item.map_to_window(geometry.origin), geometry.size);
+            if rect.contains(position) && item.is_visible() {
+                if let Some(id) = ElementHandle(item.downgrade()).id() {
+                    result = Some(id);
+                }
+            }
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+    result
+}
+
+/// Returns the id of the topmost element at the position `x`, `y` of the window of `component`,
+/// in logical pixels, to test which element a click at that position would hit.
+///
+/// The id is qualified with the name of the component, as in
+/// [`ElementHandle::find_by_element_id()`]. Elements without an id are skipped, so that the
+/// element under the position is found even when its children cover it. An element covers the
+/// positions within its geometry, regardless of whether it handles the mouse events.
+/// Returns None if no element with an id covers the position, or if no debug information was
+/// generated.
+pub fn element_id_at_position(
+    component: &impl i_slint_core::api::ComponentHandle,
+    x: f32,
+    y: f32,
+) -> Option<SharedString> {
+    let item_tree = WindowInner::from_pub(component.window()).component();
+    item_tree_id_at_position(&item_tree, LogicalPoint::new(x, y))
+}
+
+/// Panics if the topmost element at the position `x`, `y` of the window of `component`, in
+/// logical pixels, isn't the one with the id `expected_id`.
+///
+/// The id must be qualified with the name of the component, as in
+/// [`ElementHandle::find_by_element_id()`]. See [`element_id_at_position()`] for how the element
+/// is found.
+#[track_caller]
+pub fn assert_element_at_position(
+    component: &impl i_slint_core::api::ComponentHandle,
+    x: f32,
+    y: f32,
+    expected_id: &str,
+) {
+    let id = element_id_at_position(component, x, y);
+    if id.as_deref() != Some(expected_id) {
+        panic!(
+            "The element at ({x}, {y}) is {}, expected {expected_id}",
+            id.as_deref().unwrap_or("none")
+        );
+    }
+}
+
+/// Returns the position of the first item with the id `element_id` in the order in which the
+/// items of `item_tree` are rendered, from back to front.
+pub(crate) fn rendering_order(item_tree: &ItemTreeRc, element_id: &str) -> Option<usize> {
+    let mut position = 0;
+    let mut result = None;
+    i_slint_core::item_tree::visit_items(
+        item_tree,
+        TraversalOrder::BackToFront,
+        |parent_tree, _, index, _| {
+            if item_has_element_id(&ItemRc::new(parent_tree.clone(), index), element_id) {
+                result = Some(position);
+                return ItemVisitorResult::Abort;
+            }
+            position += 1;
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+    result
+}
+
+/// Panics if the element with the id `top_id` isn't drawn above the element with the id
+/// `bottom_id` in the window of `component`.
+///
+/// The ids must be qualified with the name of the component, as in
+/// [`ElementHandle::find_by_element_id()`]. The elements are drawn in the order of the tree of
+/// elements: children above their parent, and later siblings above the earlier ones. Only that
+/// order is checked, regardless of whether the elements overlap.
+#[track_caller]
+pub fn assert_z_order(
+    component: &impl i_slint_core::api::ComponentHandle,
+    top_id: &str,
+    bottom_id: &str,
+) {
+    let item_tree = WindowInner::from_pub(component.window()).component();
+    let Some(top) = rendering_order(&item_tree, top_id) else {
+        panic!("No element with the id {top_id} was found");
+    };
+    let Some(bottom) = rendering_order(&item_tree, bottom_id) else {
+        panic!("No element with the id {bottom_id} was found");
+    };
+    if top <= bottom {
+        panic!("{top_id} is drawn below {bottom_id}");
+    }
+}
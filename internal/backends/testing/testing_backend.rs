@@ -1,13 +1,17 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
 
-use i_slint_core::api::PhysicalSize;
+use i_slint_core::api::{PhysicalPosition, PhysicalSize};
 use i_slint_core::graphics::euclid::{Point2D, Size2D};
 use i_slint_core::graphics::FontRequest;
+use i_slint_core::item_rendering::{DirtyRegion, PartialRenderer, PartialRenderingCache};
+use i_slint_core::item_tree::ItemWeak;
+use i_slint_core::items::ColorScheme;
 use i_slint_core::lengths::{LogicalLength, LogicalPoint, LogicalRect, LogicalSize, ScaleFactor};
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::{Renderer, RendererSealed};
-use i_slint_core::window::{InputMethodRequest, WindowAdapter, WindowAdapterInternal};
+use i_slint_core::window::{InputMethodRequest, WindowAdapter, WindowAdapterInternal, WindowInner};
+use i_slint_core::{Property, SharedString};
 
 use std::cell::{Cell, RefCell};
 use std::pin::Pin;
@@ -36,11 +40,22 @@ impl i_slint_core::platform::Platform for TestingBackend {
     fn create_window_adapter(
         &self,
     ) -> Result<Rc<dyn WindowAdapter>, i_slint_core::platform::PlatformError> {
+        LIVE_WINDOWS.with(|count| count.set(count.get() + 1));
         Ok(Rc::new_cyclic(|self_weak| TestingWindow {
             window: i_slint_core::api::Window::new(self_weak.clone() as _),
             size: Default::default(),
+            position: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            needs_redraw: Default::default(),
+            rendered_frames: Default::default(),
+            partial_rendering_cache: Default::default(),
+            force_dirty: Default::default(),
+            force_screen_refresh: Default::default(),
+            color_scheme: Box::pin(Property::new(ColorScheme::Unknown)),
+            painting_rects: Default::default(),
+            repaint_region: Default::default(),
+            title: Default::default(),
         }))
     }
 
@@ -51,13 +66,19 @@ fn duration_since_start(&self) -> core::time::Duration {
 
     fn set_clipboard_text(&self, text: &str, clipboard: i_slint_core::platform::Clipboard) {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            *self.clipboard.lock().unwrap() = Some(text.into());
+            match crate::clipboard_api::installed_mock_clipboard() {
+                Some(mock) => *mock.borrow_mut() = Some(text.into()),
+                None => *self.clipboard.lock().unwrap() = Some(text.into()),
+            }
         }
     }
 
     fn clipboard_text(&self, clipboard: i_slint_core::platform::Clipboard) -> Option<String> {
         if clipboard == i_slint_core::platform::Clipboard::DefaultClipboard {
-            self.clipboard.lock().unwrap().clone()
+            match crate::clipboard_api::installed_mock_clipboard() {
+                Some(mock) => mock.borrow().clone(),
+                None => self.clipboard.lock().unwrap().clone(),
+            }
         } else {
             None
         }
@@ -89,8 +110,91 @@ fn new_event_loop_proxy(&self) -> Option<Box<dyn i_slint_core::platform::EventLo
 pub struct TestingWindow {
     window: i_slint_core::api::Window,
     size: Cell<PhysicalSize>,
+    position: Cell<PhysicalPosition>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    needs_redraw: Cell<bool>,
+    rendered_frames: Cell<u32>,
+    partial_rendering_cache: RefCell<PartialRenderingCache>,
+    force_dirty: RefCell<DirtyRegion>,
+    force_screen_refresh: Cell<bool>,
+    color_scheme: Pin<Box<Property<ColorScheme>>>,
+    painting_rects: RefCell<Vec<(ItemWeak, LogicalRect)>>,
+    repaint_region: Cell<LogicalRect>,
+    title: RefCell<SharedString>,
+}
+
+impl TestingWindow {
+    /// Renders a frame if a redraw was requested since the last frame.
+    /// Returns true if a frame was rendered.
+    ///
+    /// No pixels are produced, but the items are visited the same way as a real renderer would.
+    pub fn draw_if_needed(&self) -> bool {
+        if !self.needs_redraw.replace(false) {
+            return false;
+        }
+        let window = WindowInner::from_pub(&self.window);
+        let size = self.size().to_logical(window.scale_factor());
+        let size = LogicalSize::new(size.width, size.height);
+        let mut dirty_region = self.force_dirty.take();
+        if self.force_screen_refresh.take() {
+            dirty_region = LogicalRect::from_size(size).into();
+        }
+        let mut renderer = PartialRenderer::new(
+            &self.partial_rendering_cache,
+            dirty_region,
+            crate::item_renderer::TestingItemRenderer::new(window, size, &self.painting_rects),
+        );
+        window.draw_contents(|components| {
+            for (component, origin) in components {
+                renderer.compute_dirty_regions(component, *origin, size);
+            }
+            self.repaint_region.set(renderer.dirty_region.bounding_rect());
+            for (component, origin) in components {
+                i_slint_core::item_rendering::render_component_items(
+                    component,
+                    &mut renderer,
+                    *origin,
+                );
+            }
+        });
+        self.rendered_frames.set(self.rendered_frames.get() + 1);
+        self.painting_rects.borrow_mut().retain(|(item, _)| item.upgrade().is_some());
+        true
+    }
+
+    /// Returns the rectangle, in window coordinates, in which `item` was painted by the last
+    /// frame that painted it, or None if it was never painted.
+    pub fn painting_rect(&self, item: &ItemWeak) -> Option<LogicalRect> {
+        self.painting_rects.borrow().iter().find(|(i, _)| i == item).map(|(_, rect)| *rect)
+    }
+
+    /// Returns the bounding rectangle, in window coordinates, of the region that was repainted by
+    /// the last frame.
+    pub fn repaint_region(&self) -> LogicalRect {
+        self.repaint_region.get()
+    }
+
+    /// Returns the number of frames that were rendered with [`Self::draw_if_needed()`].
+    pub fn rendered_frames(&self) -> u32 {
+        self.rendered_frames.get()
+    }
+
+    /// Returns the title of the window, as last set by Slint with `update_window_properties`.
+    pub fn title(&self) -> SharedString {
+        self.title.borrow().clone()
+    }
+
+    /// Overrides the color scheme that the window reports as being the one of the system.
+    pub fn set_color_scheme(&self, scheme: ColorScheme) {
+        self.color_scheme.as_ref().set(scheme);
+    }
+}
+
+impl Drop for TestingWindow {
+    fn drop(&mut self) {
+        LIVE_WINDOWS.with(|count| count.set(count.get() - 1));
+    }
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -105,6 +209,10 @@ fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest
     fn set_mouse_cursor(&self, cursor: i_slint_core::items::MouseCursor) {
         self.mouse_cursor.set(cursor);
     }
+
+    fn color_scheme(&self) -> ColorScheme {
+        self.color_scheme.as_ref().get()
+    }
 }
 
 impl WindowAdapter for TestingWindow {
@@ -120,6 +228,14 @@ fn size(&self) -> PhysicalSize {
         }
     }
 
+    fn position(&self) -> Option<PhysicalPosition> {
+        Some(self.position.get())
+    }
+
+    fn set_position(&self, position: i_slint_core::api::WindowPosition) {
+        self.position.set(position.to_physical(self.window.scale_factor()))
+    }
+
     fn set_size(&self, size: i_slint_core::api::WindowSize) {
         self.window.dispatch_event(i_slint_core::platform::WindowEvent::Resized {
             size: size.to_logical(1.),
@@ -131,7 +247,12 @@ fn renderer(&self) -> &dyn Renderer {
         self
     }
 
+    fn request_redraw(&self) {
+        self.needs_redraw.set(true);
+    }
+
     fn update_window_properties(&self, properties: i_slint_core::window::WindowProperties<'_>) {
+        *self.title.borrow_mut() = properties.title();
         if self.size.get().width == 0 {
             let c = properties.layout_constraints();
             self.size.set(c.preferred.to_physical(self.window.scale_factor()));
@@ -196,11 +317,53 @@ fn default_font_size(&self) -> LogicalLength {
         LogicalLength::new(10.)
     }
 
+    fn free_graphics_resources(
+        &self,
+        _component: i_slint_core::item_tree::ItemTreeRef,
+        items: &mut dyn Iterator<Item = Pin<i_slint_core::items::ItemRef<'_>>>,
+    ) -> Result<(), PlatformError> {
+        for item in items {
+            item.cached_rendering_data_offset()
+                .release(&mut self.partial_rendering_cache.borrow_mut());
+        }
+        // The location of the removed items isn't known anymore, so refresh everything.
+        self.force_screen_refresh.set(true);
+        Ok(())
+    }
+
+    fn mark_dirty_region(&self, region: DirtyRegion) {
+        self.force_dirty.replace_with(|r| r.union(&region));
+    }
+
     fn set_window_adapter(&self, _window_adapter: &Rc<dyn WindowAdapter>) {
-        // No-op since TestingWindow is also the WindowAdapter
+        // TestingWindow is also the WindowAdapter, so there's only the cache to reset.
+        self.partial_rendering_cache.borrow_mut().clear();
     }
 }
 
+thread_local! {
+    /// The number of `TestingWindow`s of this thread that were created and are not dropped yet.
+    static LIVE_WINDOWS: Cell<usize> = Cell::new(0);
+}
+
+/// Returns the number of windows created by the testing backend in this thread that still exist.
+pub(crate) fn live_window_count() -> usize {
+    LIVE_WINDOWS.with(|count| count.get())
+}
+
+/// Calls `callback` with the `TestingWindow` that is associated with the given window.
+/// Returns None if the window was not created by the testing backend.
+pub(crate) fn with_testing_window<R>(
+    window: &i_slint_core::api::Window,
+    callback: impl FnOnce(&TestingWindow) -> R,
+) -> Option<R> {
+    WindowInner::from_pub(window)
+        .window_adapter()
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<TestingWindow>())
+        .map(callback)
+}
+
 enum Event {
     Quit,
     Event(Box<dyn FnOnce() + Send>),
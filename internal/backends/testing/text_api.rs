@@ -0,0 +1,258 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::search_api::find_descendant;
+use crate::ElementHandle;
+use i_slint_core::graphics::FontRequest;
+use i_slint_core::items::{ItemRc, Text, TextInput};
+pub use i_slint_core::items::{TextHorizontalAlignment, TextVerticalAlignment, TextWrap};
+use i_slint_core::window::WindowInner;
+use i_slint_core::SharedString;
+
+/// Returns the item of `element` if it is a `Text` or a `TextInput`, or else its first descendant
+/// that is one of these, such as the text of a `Button` or the input of a `LineEdit`.
+fn find_text_item(element: &ElementHandle) -> Option<ItemRc> {
+    let is_text =
+        |item: &ItemRc| item.downcast::<Text>().is_some() || item.downcast::<TextInput>().is_some();
+    let item = element.item_rc()?;
+    if is_text(&item) {
+        Some(item)
+    } else {
+        find_descendant(&item, &is_text)
+    }
+}
+
+/// Returns the font with which the text of `element` is rendered, taking the default font
+/// properties of the window into account.
+fn font_request(element: &ElementHandle) -> Option<FontRequest> {
+    let item = find_text_item(element)?;
+    let mut window_adapter = None;
+    vtable::VRc::borrow_pin(item.item_tree()).as_ref().window_adapter(true, &mut window_adapter);
+    let window_adapter = window_adapter?;
+    match item.downcast::<Text>() {
+        Some(text) => {
+            Some(text.as_pin_ref().font_request(WindowInner::from_pub(window_adapter.window())))
+        }
+        None => Some(item.downcast::<TextInput>()?.as_pin_ref().font_request(&window_adapter)),
+    }
+}
+
+/// Returns the font family with which the text of `element` is rendered, or an empty string for
+/// the default font of the platform.
+pub(crate) fn font_family(element: &ElementHandle) -> Option<SharedString> {
+    Some(font_request(element)?.family.unwrap_or_default())
+}
+
+/// Panics if the text of `element` isn't rendered with the font family `expected_family`. Use an
+/// empty string to check that the default font of the platform is used.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these. The family is
+/// the one of its `font-family` property if set, and the `default-font-family` of the window
+/// otherwise.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         default-font-family: "Noto Sans";
+///         Text { text: "Title"; font-family: "Noto Serif"; }
+///         Text { y: 50px; text: "Body"; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let title = ElementHandle::find_by_accessible_label(&app, "Title").next().unwrap();
+/// i_slint_backend_testing::assert_font_family(&title, "Noto Serif");
+/// let body = ElementHandle::find_by_accessible_label(&app, "Body").next().unwrap();
+/// i_slint_backend_testing::assert_font_family(&body, "Noto Sans");
+/// ```
+#[track_caller]
+pub fn assert_font_family(element: &ElementHandle, expected_family: &str) {
+    let Some(family) = font_family(element) else {
+        panic!("The element is not, and doesn't contain, a Text or a TextInput");
+    };
+    if family != expected_family {
+        panic!("The font family of the text is {family:?}, expected {expected_family:?}");
+    }
+}
+
+/// Returns the font size with which the text of `element` is rendered, in logical pixels, or None
+/// if it is rendered with the default font size of the platform. The outer None is for elements
+/// that don't contain any text.
+pub(crate) fn font_size(element: &ElementHandle) -> Option<Option<f32>> {
+    Some(font_request(element)?.pixel_size.map(|size| size.get()))
+}
+
+/// Panics if the text of `element` isn't rendered with the font size `expected_px`, in logical
+/// pixels, within 0.01.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these. The size is
+/// the one of its `font-size` property if set, and the `default-font-size` of the window
+/// otherwise. The assertion fails if neither is set, since the size then depends on the platform.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         default-font-size: 14px;
+///         Text { text: "Title"; font-size: 24px; }
+///         Text { y: 50px; text: "Body"; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let title = ElementHandle::find_by_accessible_label(&app, "Title").next().unwrap();
+/// i_slint_backend_testing::assert_font_size(&title, 24.);
+/// let body = ElementHandle::find_by_accessible_label(&app, "Body").next().unwrap();
+/// i_slint_backend_testing::assert_font_size(&body, 14.);
+/// ```
+#[track_caller]
+pub fn assert_font_size(element: &ElementHandle, expected_px: f32) {
+    let Some(size) = font_size(element) else {
+        panic!("The element is not, and doesn't contain, a Text or a TextInput");
+    };
+    let Some(size) = size else {
+        panic!("The text is rendered with the default font size of the platform, expected {expected_px}px");
+    };
+    if (size - expected_px).abs() > 0.01 {
+        panic!("The font size of the text is {size}px, expected {expected_px}px");
+    }
+}
+
+/// Returns the `horizontal-alignment` and `vertical-alignment` properties of the text of
+/// `element`.
+pub(crate) fn text_alignment(
+    element: &ElementHandle,
+) -> Option<(TextHorizontalAlignment, TextVerticalAlignment)> {
+    let item = find_text_item(element)?;
+    match item.downcast::<Text>() {
+        Some(text) => {
+            let text = text.as_pin_ref();
+            Some((text.horizontal_alignment(), text.vertical_alignment()))
+        }
+        None => {
+            let text_input = item.downcast::<TextInput>()?;
+            let text_input = text_input.as_pin_ref();
+            Some((text_input.horizontal_alignment(), text_input.vertical_alignment()))
+        }
+    }
+}
+
+/// Panics if the `horizontal-alignment` and `vertical-alignment` properties of the text of
+/// `element` don't match `expected_horizontal` and `expected_vertical`.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::{ElementHandle, TextHorizontalAlignment, TextVerticalAlignment};
+/// slint::slint!{
+///     export component App inherits Window {
+///         Text {
+///             text: "Price";
+///             horizontal-alignment: right;
+///             vertical-alignment: center;
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let price = ElementHandle::find_by_accessible_label(&app, "Price").next().unwrap();
+/// i_slint_backend_testing::assert_text_alignment(
+///     &price,
+///     TextHorizontalAlignment::Right,
+///     TextVerticalAlignment::Center,
+/// );
+/// ```
+#[track_caller]
+pub fn assert_text_alignment(
+    element: &ElementHandle,
+    expected_horizontal: TextHorizontalAlignment,
+    expected_vertical: TextVerticalAlignment,
+) {
+    let Some((horizontal, vertical)) = text_alignment(element) else {
+        panic!("The element is not, and doesn't contain, a Text or a TextInput");
+    };
+    if (horizontal, vertical) != (expected_horizontal, expected_vertical) {
+        panic!(
+            "The text is aligned {horizontal:?} and {vertical:?}, expected {expected_horizontal:?} and {expected_vertical:?}"
+        );
+    }
+}
+
+/// Returns the `wrap` property of the text of `element`.
+pub(crate) fn text_wrap(element: &ElementHandle) -> Option<TextWrap> {
+    let item = find_text_item(element)?;
+    match item.downcast::<Text>() {
+        Some(text) => Some(text.as_pin_ref().wrap()),
+        None => Some(item.downcast::<TextInput>()?.as_pin_ref().wrap()),
+    }
+}
+
+/// Panics if the `wrap` property of the text of `element` doesn't match `expected`.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::{ElementHandle, TextWrap};
+/// slint::slint!{
+///     export component App inherits Window {
+///         Text {
+///             width: 100px;
+///             text: "Description";
+///             wrap: word-wrap;
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let description = ElementHandle::find_by_accessible_label(&app, "Description").next().unwrap();
+/// i_slint_backend_testing::assert_text_wrap(&description, TextWrap::WordWrap);
+/// ```
+#[track_caller]
+pub fn assert_text_wrap(element: &ElementHandle, expected: TextWrap) {
+    let Some(wrap) = text_wrap(element) else {
+        panic!("The element is not, and doesn't contain, a Text or a TextInput");
+    };
+    if wrap != expected {
+        panic!("The text wraps with {wrap:?}, expected {expected:?}");
+    }
+}
+
+/// Returns the `letter-spacing` property of the text of `element`, in logical pixels.
+pub(crate) fn letter_spacing(element: &ElementHandle) -> Option<f32> {
+    let item = find_text_item(element)?;
+    match item.downcast::<Text>() {
+        Some(text) => Some(text.as_pin_ref().letter_spacing().get()),
+        None => Some(item.downcast::<TextInput>()?.as_pin_ref().letter_spacing().get()),
+    }
+}
+
+/// Panics if the `letter-spacing` property of the text of `element` doesn't match
+/// `expected_px`, in logical pixels, within 0.01.
+///
+/// The element is a `Text`, a `TextInput`, or a widget that contains one of these.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     export component App inherits Window {
+///         Text {
+///             text: "HEADLINE";
+///             letter-spacing: 1.5px;
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let headline = ElementHandle::find_by_accessible_label(&app, "HEADLINE").next().unwrap();
+/// i_slint_backend_testing::assert_letter_spacing(&headline, 1.5);
+/// ```
+#[track_caller]
+pub fn assert_letter_spacing(element: &ElementHandle, expected_px: f32) {
+    let Some(spacing) = letter_spacing(element) else {
+        panic!("The element is not, and doesn't contain, a Text or a TextInput");
+    };
+    if (spacing - expected_px).abs() > 0.01 {
+        panic!("The letter spacing of the text is {spacing}px, expected {expected_px}px");
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use i_slint_core::timers::Timer;
+
+/// Panics if `timer` isn't running.
+///
+/// A timer runs from the moment it is started until it is stopped. A single-shot timer also stops
+/// after its callback was invoked.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::{Timer, TimerMode};
+/// let timer = Timer::default();
+/// i_slint_backend_testing::assert_timer_inactive(&timer);
+/// timer.start(TimerMode::Repeated, std::time::Duration::from_millis(100), || {});
+/// i_slint_backend_testing::assert_timer_active(&timer);
+/// timer.stop();
+/// i_slint_backend_testing::assert_timer_inactive(&timer);
+/// ```
+#[track_caller]
+pub fn assert_timer_active(timer: &Timer) {
+    if !timer.running() {
+        panic!("The timer isn't running");
+    }
+}
+
+/// Panics if `timer` is running.
+///
+/// See [`assert_timer_active()`] for when a timer runs.
+#[track_caller]
+pub fn assert_timer_inactive(timer: &Timer) {
+    if timer.running() {
+        panic!("The timer is running");
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::input_api::{element_center, simulate_click};
+use crate::search_api::{find_descendant, search_item};
+use crate::ElementHandle;
+use i_slint_core::accessibility::AccessibleStringProperty;
+use i_slint_core::api::{ComponentHandle, Window};
+use i_slint_core::items::{
+    AccessibleRole, Clip, ClippedImage, Flickable, ImageItem, ItemRc, ItemVTable, TextInput,
+};
+use i_slint_core::window::WindowInner;
+use i_slint_core::SharedString;
+use vtable::HasStaticVTable;
+
+pub use i_slint_core::items::ImageFit;
+
+/// Clicks on `combobox` to open its popup, and returns the texts shown in that popup before
+/// closing it again. Returns None if no popup was opened.
+pub(crate) fn combobox_items(
+    window: &Window,
+    combobox: &ElementHandle,
+) -> Option<Vec<SharedString>> {
+    if !combobox.is_valid() {
+        return None;
+    }
+    simulate_click(window, element_center(combobox));
+    let window_inner = WindowInner::from_pub(window);
+    let popup = window_inner.active_popup_component()?;
+    let items = search_item(&popup, |item| {
+        item.is_accessible() && item.accessible_role() == AccessibleRole::Text
+    })
+    .into_iter()
+    .filter_map(|item| item.upgrade()?.accessible_string_property(AccessibleStringProperty::Label))
+    .collect();
+    window_inner.close_popup();
+    Some(items)
+}
+
+/// Panics if the items of the `ComboBox` widget `combobox` aren't `expected`, in that order.
+///
+/// The items are read from the popup of the combo box, which is opened by simulating a click on
+/// the combo box, and closed again afterwards. The current value of the combo box doesn't change.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use i_slint_backend_testing::ElementHandle;
+/// slint::slint!{
+///     import { ComboBox } from "std-widgets.slint";
+///     export component App inherits Window {
+///         ComboBox {
+///             model: ["Apple", "Banana"];
+///             accessible-label: "Fruits";
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let fruits = ElementHandle::find_by_accessible_label(&app, "Fruits").next().unwrap();
+/// i_slint_backend_testing::assert_combobox_items(&app, &fruits, &["Apple", "Banana"]);
+/// ```
+#[track_caller]
+pub fn assert_combobox_items(
+    component: &impl ComponentHandle,
+    combobox: &ElementHandle,
+    expected: &[&str],
+) {
+    let Some(items) = combobox_items(component.window(), combobox) else {
+        panic!("Clicking on the element didn't open the popup of a combo box");
+    };
+    if items.iter().map(|item| item.as_str()).ne(expected.iter().copied()) {
+        panic!("The items of the combo box are {items:?}, expected {expected:?}");
+    }
+}
+
+/// Panics if the range of the `Slider` widget `slider` isn't from `expected_minimum` to
+/// `expected_maximum`.
+///
+/// The range is read from the `accessible-value-minimum` and `accessible-value-maximum`
+/// properties, which the sliders of all the styles forward from their `minimum` and `maximum`.
+#[track_caller]
+pub fn assert_slider_range(slider: &ElementHandle, expected_minimum: f32, expected_maximum: f32) {
+    let (Some(minimum), Some(maximum)) =
+        (slider.accessible_value_minimum(), slider.accessible_value_maximum())
+    else {
+        panic!("The element is not a slider");
+    };
+    if (minimum, maximum) != (expected_minimum, expected_maximum) {
+        panic!(
+            "The range of the slider is {minimum}..{maximum}, expected {expected_minimum}..{expected_maximum}"
+        );
+    }
+}
+
+/// Panics if `element` doesn't have the accessible `role`, or if its `accessible-checked`
+/// property isn't `expected`. `widget` is the name of the widget for the panic messages.
+#[track_caller]
+fn assert_checked_state(
+    element: &ElementHandle,
+    role: AccessibleRole,
+    widget: &str,
+    expected: bool,
+) {
+    if element.accessible_role() != Some(role) {
+        panic!("The element is not a {widget}");
+    }
+    let checked = element.accessible_checked().unwrap_or_default();
+    if checked != expected {
+        let state = |checked| if checked { "checked" } else { "unchecked" };
+        panic!("The {widget} is {}, expected it {}", state(checked), state(expected));
+    }
+}
+
+/// Panics if the `CheckBox` widget `checkbox` isn't checked when `expected` is true, or if it is
+/// checked when `expected` is false.
+///
+/// The state is read from the `accessible-checked` property, which the check boxes of all the
+/// styles forward from their `checked` property.
+///
+/// This doesn't support tri-state check boxes: Slint's `CheckBox` has no indeterminate state, and
+/// no `check-state` property to read it from, so only the checked and unchecked states can be
+/// tested.
+#[track_caller]
+pub fn assert_checkbox_checked(checkbox: &ElementHandle, expected: bool) {
+    assert_checked_state(checkbox, AccessibleRole::Checkbox, "check box", expected);
+}
+
+/// Panics if the `progress` of the `ProgressIndicator` widget `indicator` doesn't match
+/// `expected`, within 0.0001.
+///
+/// The progress is read from the `accessible-value` property, which the progress indicators of
+/// all the styles forward from their `progress`.
+#[track_caller]
+pub fn assert_progress_value(indicator: &ElementHandle, expected: f32) {
+    if indicator.accessible_role() != Some(AccessibleRole::ProgressIndicator) {
+        panic!("The element is not a progress indicator");
+    }
+    let value = indicator.accessible_value();
+    let progress = value.as_ref().and_then(|value| value.parse::<f32>().ok());
+    if !progress.is_some_and(|progress| (progress - expected).abs() <= 0.0001) {
+        panic!("The progress is {value:?}, expected {expected}");
+    }
+}
+
+/// Panics if the `value` of the `SpinBox` widget `spinbox` isn't `expected`.
+///
+/// The value is read from the `accessible-value` property, which the spin boxes of all the styles
+/// forward from their `value`.
+#[track_caller]
+pub fn assert_spin_box_value(spinbox: &ElementHandle, expected: i32) {
+    if spinbox.accessible_role() != Some(AccessibleRole::Spinbox) {
+        panic!("The element is not a spin box");
+    }
+    let value = spinbox.accessible_value();
+    if value.as_ref().and_then(|value| value.parse::<i32>().ok()) != Some(expected) {
+        panic!("The value of the spin box is {value:?}, expected {expected}");
+    }
+}
+
+/// Returns the index of the current tab of the `TabWidget` widget `tab_widget`.
+///
+/// The content of each tab is only visible while the `current-index` of the tab widget is the
+/// index of that tab. The compiler wraps such a content into a `Clip` element that clips while the
+/// content isn't visible, so the current tab is the one whose `Clip` doesn't clip.
+pub(crate) fn tab_widget_current_index(tab_widget: &ElementHandle) -> Option<i32> {
+    let item = tab_widget.item_rc()?;
+    let mut has_tab_bar = false;
+    let mut contents = Vec::new();
+    let mut child = item.first_child();
+    while let Some(item) = child {
+        if item.is_accessible() && item.accessible_role() == AccessibleRole::TabList {
+            has_tab_bar = true;
+        } else if let Some(clip) = item.downcast::<Clip>() {
+            contents.push(clip.as_pin_ref().clip());
+        }
+        child = item.next_sibling();
+    }
+    if !has_tab_bar {
+        return None;
+    }
+    contents.iter().position(|clipped| !clipped)?.try_into().ok()
+}
+
+/// Panics if the current tab of the `TabWidget` widget `tab_widget` isn't the one at
+/// `expected_index`.
+///
+/// The current tab is the one whose content the tab widget shows, which follows its
+/// `current-index` property. Moving the keyboard focus between the tabs doesn't change it.
+#[track_caller]
+pub fn assert_tab_selected(tab_widget: &ElementHandle, expected_index: i32) {
+    let Some(index) = tab_widget_current_index(tab_widget) else {
+        panic!("The element is not a tab widget");
+    };
+    if index != expected_index {
+        panic!("The current tab is the one at index {index}, expected {expected_index}");
+    }
+}
+
+/// Panics if the `Switch` widget `switch` isn't checked.
+///
+/// The state is read from the `accessible-checked` property, which the switches of all the styles
+/// forward from their `checked` property.
+#[track_caller]
+pub fn assert_switch_checked(switch: &ElementHandle) {
+    assert_checked_state(switch, AccessibleRole::Switch, "switch", true);
+}
+
+/// Panics if the `Switch` widget `switch` is checked. See [`assert_switch_checked()`].
+#[track_caller]
+pub fn assert_switch_unchecked(switch: &ElementHandle) {
+    assert_checked_state(switch, AccessibleRole::Switch, "switch", false);
+}
+
+/// Returns the item of `element` if it is a `T`, or else its first descendant that is a `T`.
+pub(crate) fn find_self_or_descendant<T: HasStaticVTable<ItemVTable>>(
+    element: &ElementHandle,
+) -> Option<ItemRc> {
+    let is_t = |item: &ItemRc| item.downcast::<T>().is_some();
+    let item = element.item_rc()?;
+    if is_t(&item) {
+        Some(item)
+    } else {
+        find_descendant(&item, &is_t)
+    }
+}
+
+/// Returns the start and end byte offsets of the text that is selected in `element`, which is
+/// either a `TextInput` or a widget that contains one, such as `LineEdit` or `TextEdit`.
+pub(crate) fn text_selection(element: &ElementHandle) -> Option<(usize, usize)> {
+    let item = find_self_or_descendant::<TextInput>(element)?;
+    Some(item.downcast::<TextInput>()?.as_pin_ref().selection_anchor_and_cursor())
+}
+
+/// Panics if the text that is selected in `element` doesn't start at the byte offset
+/// `expected_start` and end at `expected_end`.
+///
+/// The element is either a `TextInput` or a widget that contains one, such as `LineEdit` or
+/// `TextEdit`. The start is the smaller of the cursor and anchor positions, regardless of the
+/// direction in which the text was selected. Without selection, both offsets are the position of
+/// the cursor.
+#[track_caller]
+pub fn assert_text_selection(element: &ElementHandle, expected_start: usize, expected_end: usize) {
+    let Some((start, end)) = text_selection(element) else {
+        panic!("The element is not, and doesn't contain, a TextInput");
+    };
+    if (start, end) != (expected_start, expected_end) {
+        panic!(
+            "The selection is from byte {start} to {end}, expected from {expected_start} to {expected_end}"
+        );
+    }
+}
+
+/// Returns the `viewport-x` and `viewport-y` properties of `element`, which is either a
+/// `Flickable` or a widget that contains one, such as `ScrollView` or `ListView`.
+pub(crate) fn scroll_position(element: &ElementHandle) -> Option<(f32, f32)> {
+    let item = find_self_or_descendant::<Flickable>(element)?;
+    let flickable = item.downcast::<Flickable>()?;
+    let flickable = flickable.as_pin_ref();
+    Some((flickable.viewport_x().get(), flickable.viewport_y().get()))
+}
+
+/// Panics if the `viewport-x` and `viewport-y` properties of the `ListView` widget `list_view`
+/// don't match `expected_x` and `expected_y`, within 0.01 logical pixels.
+///
+/// The element can also be a `ScrollView` or a `Flickable`. The viewport moves up and to the left
+/// when scrolling, so the coordinates are zero or negative.
+#[track_caller]
+pub fn assert_list_view_scroll_position(
+    list_view: &ElementHandle,
+    expected_x: f32,
+    expected_y: f32,
+) {
+    let Some((x, y)) = scroll_position(list_view) else {
+        panic!("The element is not, and doesn't contain, a Flickable");
+    };
+    if (x - expected_x).abs() > 0.01 || (y - expected_y).abs() > 0.01 {
+        panic!("The scroll position is ({x}, {y}), expected ({expected_x}, {expected_y})");
+    }
+}
+
+/// Returns the width and height of the `source` of `element`, which is an `Image`.
+pub(crate) fn image_size(element: &ElementHandle) -> Option<(f32, f32)> {
+    let item = element.item_rc()?;
+    let size = if let Some(image) = item.downcast::<ImageItem>() {
+        image.as_pin_ref().source().size()
+    } else {
+        item.downcast::<ClippedImage>()?.as_pin_ref().source().size()
+    };
+    Some((size.width as f32, size.height as f32))
+}
+
+/// Panics if the `source.width` and `source.height` of the `Image` element `image` aren't
+/// `expected_width` and `expected_height`.
+///
+/// This is the size of the image in pixels, regardless of the `width` and `height` with which the
+/// element displays it. It is zero when the image failed to load.
+#[track_caller]
+pub fn assert_image_size(image: &ElementHandle, expected_width: f32, expected_height: f32) {
+    let Some((width, height)) = image_size(image) else {
+        panic!("The element is not an Image");
+    };
+    if (width, height) != (expected_width, expected_height) {
+        panic!("The size of the image is {width}x{height}, expected {expected_width}x{expected_height}");
+    }
+}
+
+/// Returns the `image-fit` property of `element`, which is an `Image`.
+pub(crate) fn image_fit(element: &ElementHandle) -> Option<ImageFit> {
+    let item = element.item_rc()?;
+    if let Some(image) = item.downcast::<ImageItem>() {
+        Some(image.as_pin_ref().image_fit())
+    } else {
+        Some(item.downcast::<ClippedImage>()?.as_pin_ref().image_fit())
+    }
+}
+
+/// Panics if the `image-fit` property of the `Image` element `image` isn't `expected`.
+///
+/// The fit mode determines how the source is scaled when the element doesn't have the size of
+/// the image. It is `fill` by default, or `contain` for an `Image` in a layout that has no
+/// explicit `width` or `height`.
+#[track_caller]
+pub fn assert_image_scaling(image: &ElementHandle, expected: ImageFit) {
+    let Some(fit) = image_fit(image) else {
+        panic!("The element is not an Image");
+    };
+    if fit != expected {
+        panic!("The image is scaled with {fit:?}, expected {expected:?}");
+    }
+}
+
+/// Panics if the `text` of the `LineEdit` widget `search_field` isn't `expected`.
+///
+/// The text is read from the `accessible-value` property, which the line edits of all the styles
+/// forward from their `text`.
+#[track_caller]
+pub fn assert_search_text(search_field: &ElementHandle, expected: &str) {
+    if search_field.accessible_role() != Some(AccessibleRole::TextInput) {
+        panic!("The element is not a line edit");
+    }
+    let text = search_field.accessible_value();
+    if text.as_deref() != Some(expected) {
+        panic!("The text of the search field is {text:?}, expected {expected:?}");
+    }
+}
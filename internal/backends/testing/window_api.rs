@@ -0,0 +1,305 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+use crate::testing_backend::with_testing_window;
+use i_slint_core::api::ComponentHandle;
+use i_slint_core::item_tree::{visit_items, ItemVisitorResult, TraversalOrder};
+pub use i_slint_core::items::ColorScheme;
+use i_slint_core::items::ItemRc;
+use i_slint_core::platform::WindowEvent;
+use i_slint_core::window::WindowInner;
+
+/// Overrides the color scheme that the system reports for the window of `component`.
+///
+/// Styles pick their colors based on that scheme, unless the application chose a scheme
+/// explicitly, for example through `Palette.color-scheme`. This function has no effect if the
+/// window of `component` was not created by the testing backend.
+pub fn set_system_color_scheme(component: &impl ComponentHandle, scheme: ColorScheme) {
+    with_testing_window(component.window(), |w| w.set_color_scheme(scheme));
+}
+
+/// Simulates the user resizing the window of `component` to `new_width` x `new_height` logical
+/// pixels.
+///
+/// The window receives the same resize event as with a real windowing system, so that the
+/// `width` and `height` of the `Window` element, and the layouts that depend on them, are updated.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         out property <bool> narrow: root.width < 400px;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::simulate_window_resize(&app, 300., 200.);
+/// assert!(app.get_narrow());
+/// i_slint_backend_testing::simulate_window_resize(&app, 800., 200.);
+/// assert!(!app.get_narrow());
+/// ```
+pub fn simulate_window_resize(component: &impl ComponentHandle, new_width: f32, new_height: f32) {
+    component.window().set_size(i_slint_core::api::LogicalSize::new(new_width, new_height));
+}
+
+/// Computes the geometry of all the elements of `window`, and so the layouts that it depends on.
+pub(crate) fn compute_layouts(window: &i_slint_core::api::Window) {
+    let Some(item_tree) = WindowInner::from_pub(window).try_component() else { return };
+    visit_items(
+        &item_tree,
+        TraversalOrder::BackToFront,
+        |item_tree, _, index, _| {
+            ItemRc::new(item_tree.clone(), index).geometry();
+            ItemVisitorResult::Continue(())
+        },
+        (),
+    );
+}
+
+/// Resizes the window of `component` to `new_width` x `new_height` logical pixels, and returns the
+/// time that it takes to compute the layouts of its elements for that size.
+///
+/// Layouts are computed lazily, when the geometry of an element is needed, so the measured time
+/// covers the resize and the computation of the geometry of all the elements of the window, but
+/// not the rendering. If the window already has that size, or if its size is fixed, the layouts
+/// aren't computed again. Use it to detect regressions in the time that a layout takes to adapt
+/// to a new size.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         VerticalLayout {
+///             for i in 100: HorizontalLayout {
+///                 Rectangle { }
+///                 Text { text: "Row " + i; }
+///             }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let duration = i_slint_backend_testing::measure_layout_time(&app, 800., 600.);
+/// assert!(duration < std::time::Duration::from_secs(1));
+/// ```
+pub fn measure_layout_time(
+    component: &impl ComponentHandle,
+    new_width: f32,
+    new_height: f32,
+) -> std::time::Duration {
+    let start = std::time::Instant::now();
+    simulate_window_resize(component, new_width, new_height);
+    compute_layouts(component.window());
+    start.elapsed()
+}
+
+/// Calls `mutator` to change some properties, and returns how many layouts of the window of
+/// `component` had to be solved again because of that change.
+///
+/// Layouts are solved lazily, when the geometry of an element is needed, so the layouts are solved
+/// before calling `mutator`, and then the geometry of all the elements of the window is computed
+/// after it. Each layout counts once per orientation that it had to solve, and a `GridLayout`
+/// always solves both at the same time. See also [`assert_layout_passes_count`].
+///
+/// This requires the `debug-counters` feature of this crate.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         in property <length> sidebar-width: 100px;
+///         in property <color> accent: blue;
+///         HorizontalLayout {
+///             Rectangle { width: sidebar-width; }
+///             Rectangle { background: accent; }
+///         }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// use i_slint_backend_testing::count_layout_passes;
+/// assert!(count_layout_passes(&app, || app.set_sidebar_width(200.)) > 0);
+/// assert_eq!(count_layout_passes(&app, || app.set_accent(slint::Color::default())), 0);
+/// ```
+#[cfg(feature = "debug-counters")]
+pub fn count_layout_passes(component: &impl ComponentHandle, mutator: impl FnOnce()) -> u32 {
+    let window = component.window();
+    compute_layouts(window);
+    let start = i_slint_core::layout::solve_count();
+    mutator();
+    compute_layouts(window);
+    i_slint_core::layout::solve_count().wrapping_sub(start)
+}
+
+/// Calls `mutator` to change some properties, and panics if more than `max_passes` layouts of the
+/// window of `component` had to be solved again because of that change.
+///
+/// See [`count_layout_passes`] for how the layouts are counted.
+#[cfg(feature = "debug-counters")]
+#[track_caller]
+pub fn assert_layout_passes_count(
+    component: &impl ComponentHandle,
+    max_passes: u32,
+    mutator: impl FnOnce(),
+) {
+    let passes = count_layout_passes(component, mutator);
+    if passes > max_passes {
+        panic!("The change caused {passes} layout passes, expected at most {max_passes}");
+    }
+}
+
+/// Injects `event` into the window of `component`, as if it came from the windowing system.
+///
+/// This is useful to test how the application reacts to events that the user cannot trigger
+/// from within the window. For example, Slint has no separate event for an application that is
+/// paused and resumed: the platform reports it by deactivating and re-activating the window with
+/// [`WindowEvent::WindowActiveChanged`], which also removes and restores the keyboard focus.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::platform::WindowEvent;
+/// slint::slint!{
+///     export component App inherits Window {
+///         forward-focus: scope;
+///         out property <bool> focused: scope.has-focus;
+///         scope := FocusScope { }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::simulate_platform_event(&app, WindowEvent::WindowActiveChanged(false));
+/// assert!(!app.get_focused());
+/// i_slint_backend_testing::simulate_platform_event(&app, WindowEvent::WindowActiveChanged(true));
+/// assert!(app.get_focused());
+/// ```
+pub fn simulate_platform_event(component: &impl ComponentHandle, event: WindowEvent) {
+    component.window().dispatch_event(event);
+}
+
+/// Panics if the title of the window of `component` isn't `expected`.
+///
+/// The title is the one that Slint passes on to the windowing system, as set with the `title`
+/// property of the `Window` element.
+#[track_caller]
+pub fn assert_window_title(component: &impl ComponentHandle, expected: &str) {
+    let window = component.window();
+    // Backends get the new title at the next iteration of the event loop, which the tests don't run
+    WindowInner::from_pub(window).update_window_properties();
+    let Some(title) = with_testing_window(window, |w| w.title()) else {
+        panic!("The window of the component wasn't created by the testing backend");
+    };
+    if title != expected {
+        panic!("The window title is {title:?}, expected {expected:?}");
+    }
+}
+
+/// Simulates the low-memory warning that mobile platforms send to applications that should
+/// release their caches.
+///
+/// Slint doesn't notify applications of such warnings, so this function releases the caches that
+/// Slint maintains itself: the cache of decoded images is cleared and the window of `component`
+/// is redrawn, so that the elements that are visible load again the resources that they need.
+/// Use it to verify that the user interface still renders correctly afterwards.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         Rectangle { background: red; }
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// let frames = i_slint_backend_testing::with_render_counter(&app, || {
+///     i_slint_backend_testing::simulate_low_memory_warning(&app);
+/// });
+/// assert_eq!(frames, 1);
+/// ```
+pub fn simulate_low_memory_warning(component: &impl ComponentHandle) {
+    i_slint_core::graphics::cache::clear();
+    component.window().request_redraw();
+}
+
+/// Returns the size of `window`, in logical pixels.
+pub(crate) fn window_size(window: &i_slint_core::api::Window) -> i_slint_core::api::LogicalSize {
+    // The testing backend sizes new windows to their preferred size when it gets their properties
+    WindowInner::from_pub(window).update_window_properties();
+    window.size().to_logical(window.scale_factor())
+}
+
+/// Panics if the size of the window of `component` isn't `expected_width` x `expected_height`
+/// logical pixels.
+///
+/// A window gets its preferred size when it is created, for example from the `preferred-width`
+/// and `preferred-height` properties of the `Window` element, and a new size when it is resized.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window {
+///         preferred-width: 400px;
+///         preferred-height: 300px;
+///     }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::assert_window_size(&app, 400., 300.);
+/// ```
+#[track_caller]
+pub fn assert_window_size(
+    component: &impl ComponentHandle,
+    expected_width: f32,
+    expected_height: f32,
+) {
+    let size = window_size(component.window());
+    if (size.width, size.height) != (expected_width, expected_height) {
+        panic!(
+            "The window size is {}x{}, expected {expected_width}x{expected_height}",
+            size.width, size.height
+        );
+    }
+}
+
+/// Panics if the position of the window of `component` on the screen isn't `expected_x`,
+/// `expected_y` logical pixels.
+///
+/// The testing backend places new windows at the origin of the screen, and moves them when the
+/// application calls [`Window::set_position()`](i_slint_core::api::Window::set_position).
+#[track_caller]
+pub fn assert_window_position(component: &impl ComponentHandle, expected_x: f32, expected_y: f32) {
+    let window = component.window();
+    let position = window.position().to_logical(window.scale_factor());
+    if (position.x, position.y) != (expected_x, expected_y) {
+        panic!(
+            "The window position is ({}, {}), expected ({expected_x}, {expected_y})",
+            position.x, position.y
+        );
+    }
+}
+
+/// Panics if the window of `component` isn't visible.
+///
+/// A window becomes visible when `show()` is called, and hidden again with `hide()`.
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// slint::slint!{
+///     export component App inherits Window { }
+/// }
+/// let app = App::new().unwrap();
+/// i_slint_backend_testing::assert_window_hidden(&app);
+/// app.show().unwrap();
+/// i_slint_backend_testing::assert_window_visible(&app);
+/// app.hide().unwrap();
+/// i_slint_backend_testing::assert_window_hidden(&app);
+/// ```
+#[track_caller]
+pub fn assert_window_visible(component: &impl ComponentHandle) {
+    if !component.window().is_visible() {
+        panic!("The window is hidden, expected it to be visible");
+    }
+}
+
+/// Panics if the window of `component` is visible.
+///
+/// See [`assert_window_visible`] for when a window is visible.
+#[track_caller]
+pub fn assert_window_hidden(component: &impl ComponentHandle) {
+    if component.window().is_visible() {
+        panic!("The window is visible, expected it to be hidden");
+    }
+}
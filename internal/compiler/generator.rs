@@ -65,6 +65,7 @@ pub fn generate(
     format: OutputFormat,
     destination: &mut impl std::io::Write,
     doc: &Document,
+    compiler_config: &crate::CompilerConfiguration,
 ) -> std::io::Result<()> {
     #![allow(unused_variables)]
     #![allow(unreachable_code)]
@@ -77,12 +78,12 @@ pub fn generate(
     match format {
         #[cfg(feature = "cpp")]
         OutputFormat::Cpp(config) => {
-            let output = cpp::generate(doc, config);
+            let output = cpp::generate(doc, config, compiler_config);
             write!(destination, "{}", output)?;
         }
         #[cfg(feature = "rust")]
         OutputFormat::Rust => {
-            let output = rust::generate(doc);
+            let output = rust::generate(doc, compiler_config);
             write!(destination, "{}", output)?;
         }
         OutputFormat::Interpreter => {
@@ -92,7 +93,10 @@ pub fn generate(
             )); // Perhaps byte code in the future?
         }
         OutputFormat::Llr => {
-            let root = crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
+            let root = crate::llr::lower_to_item_tree::lower_to_item_tree(
+                &doc.root_component,
+                compiler_config,
+            );
             let mut output = String::new();
             crate::llr::pretty_print::pretty_print(&root, &mut output).unwrap();
             write!(destination, "{output}")?;
@@ -334,6 +334,7 @@ pub fn escape_string(str: &str) -> String {
 };
 use crate::object_tree::Document;
 use crate::parser::syntax_nodes;
+use crate::CompilerConfiguration;
 use cpp_ast::*;
 use itertools::{Either, Itertools};
 use std::cell::Cell;
@@ -534,7 +535,11 @@ fn handle_property_init(
 }
 
 /// Returns the text of the C++ code produced by the given root component
-pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
+pub fn generate(
+    doc: &Document,
+    config: Config,
+    compiler_config: &CompilerConfiguration,
+) -> impl std::fmt::Display {
     let mut file = File { namespace: config.namespace.clone(), ..Default::default() };
 
     file.includes.push("<array>".into());
@@ -764,7 +769,7 @@ pub fn generate(doc: &Document, config: Config) -> impl std::fmt::Display {
         return file;
     }
 
-    let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
+    let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component, compiler_config);
 
     // Forward-declare the root so that sub-components can access singletons, the window, etc.
     file.declarations.push(Declaration::Struct(Struct {
@@ -1400,6 +1405,54 @@ fn generate_item_tree(
         }),
     ));
 
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_element_infos".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, slint::SharedString *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "if (auto infos = reinterpret_cast<const {}*>(component.instance)->element_infos(index)) {{ *result = *infos; return true; }} else {{ return false; }}",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_property_dependencies".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, const slint::SharedString *property, slint::SharedVector<slint::SharedString> *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "if (auto dependencies = reinterpret_cast<const {}*>(component.instance)->property_dependencies(index, *property)) {{ *result = *dependencies; return true; }} else {{ return false; }}",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
+    target_struct.members.push((
+        Access::Private,
+        Declaration::Function(Function {
+            name: "item_current_state".into(),
+            signature:
+                "([[maybe_unused]] slint::private_api::ItemTreeRef component, uint32_t index, slint::SharedString *result) -> bool"
+                    .into(),
+            is_static: true,
+            statements: Some(vec![format!(
+                "if (auto state = reinterpret_cast<const {}*>(component.instance)->current_state(index)) {{ *result = *state; return true; }} else {{ return false; }}",
+                item_tree_class_name
+            )]),
+            ..Default::default()
+        }),
+    ));
+
     target_struct.members.push((
         Access::Private,
         Declaration::Function(Function {
@@ -1431,8 +1484,8 @@ fn generate_item_tree(
             "{{ visit_children, get_item_ref, get_subtree_range, get_subtree, \
                 get_item_tree, parent_node, embed_component, subtree_index, layout_info, \
                 item_geometry, accessible_role, accessible_string_property, accessibility_action, \
-                supported_accessibility_actions, window_adapter, \
-                slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
+                supported_accessibility_actions, item_element_infos, item_property_dependencies, \
+                item_current_state, window_adapter, slint::private_api::drop_in_place<{}>, slint::private_api::dealloc }}",
             item_tree_class_name
         )),
         ..Default::default()
@@ -1998,6 +2051,54 @@ fn generate_sub_component(
         supported_accessibility_actions_cases,
     );
 
+    let mut element_infos_cases = vec!["switch (index) {".to_string()];
+    element_infos_cases.extend(component.element_infos.iter().map(|(index, infos)| {
+        format!("    case {index}: return slint::SharedString(u8\"{}\");", escape_string(infos))
+    }));
+    element_infos_cases.push("}".into());
+
+    dispatch_item_function(
+        "element_infos",
+        "(uint32_t index) const -> std::optional<slint::SharedString>",
+        "",
+        element_infos_cases,
+    );
+
+    let property_dependencies_cases = component
+        .property_dependencies
+        .iter()
+        .map(|((index, prop), dependencies)| {
+            format!(
+                "if (index == {index} && property == slint::SharedString(u8\"{}\")) return slint::SharedVector<slint::SharedString>{{ {} }};",
+                escape_string(prop),
+                dependencies
+                    .iter()
+                    .map(|d| format!("slint::SharedString(u8\"{}\")", escape_string(d)))
+                    .join(", ")
+            )
+        })
+        .collect();
+
+    dispatch_item_function(
+        "property_dependencies",
+        "(uint32_t index, [[maybe_unused]] const slint::SharedString &property) const -> std::optional<slint::SharedVector<slint::SharedString>>",
+        ", property",
+        property_dependencies_cases,
+    );
+
+    let mut current_state_cases = vec!["switch (index) {".to_string()];
+    current_state_cases.extend(component.current_states.iter().map(|(index, expr)| {
+        format!("    case {index}: return {};", compile_expression(&expr.borrow(), &ctx))
+    }));
+    current_state_cases.push("}".into());
+
+    dispatch_item_function(
+        "current_state",
+        "(uint32_t index) const -> std::optional<slint::SharedString>",
+        "",
+        current_state_cases,
+    );
+
     if !children_visitor_cases.is_empty() {
         target_struct.members.push((
             field_access,
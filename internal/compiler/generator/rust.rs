@@ -20,6 +20,7 @@
     TypeResolutionContext as _,
 };
 use crate::object_tree::Document;
+use crate::CompilerConfiguration;
 use itertools::Either;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
@@ -138,7 +139,7 @@ fn set_primitive_property_value(ty: &Type, value_expression: TokenStream) -> Tok
 }
 
 /// Generate the rust code for the given component.
-pub fn generate(doc: &Document) -> TokenStream {
+pub fn generate(doc: &Document, compiler_config: &CompilerConfiguration) -> TokenStream {
     let (structs_and_enums_ids, structs_and_enum_def): (Vec<_>, Vec<_>) = doc
         .root_component
         .used_types
@@ -162,7 +163,8 @@ pub fn generate(doc: &Document) -> TokenStream {
         return TokenStream::default();
     }
 
-    let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
+    let llr =
+        crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component, compiler_config);
 
     let sub_compos = llr
         .sub_components
@@ -814,6 +816,29 @@ fn generate_sub_component(
         .map(|(index, values)| quote!(#index => #(sp::SupportedAccessibilityAction::#values)|*,))
         .collect::<Vec<_>>();
 
+    let mut item_element_infos_branch = component
+        .element_infos
+        .iter()
+        .map(|(item_index, ids)| quote!(#item_index => sp::Some(#ids.into()),))
+        .collect::<Vec<_>>();
+
+    let mut item_property_dependencies_branch = component
+        .property_dependencies
+        .iter()
+        .map(|((item_index, prop), dependencies)| {
+            quote!((#item_index, #prop) => sp::Some(sp::SharedVector::from_slice(&[#(sp::SharedString::from(#dependencies)),*])),)
+        })
+        .collect::<Vec<_>>();
+
+    let mut item_current_state_branch = component
+        .current_states
+        .iter()
+        .map(|(item_index, expr)| {
+            let e = compile_expression(&expr.borrow(), &ctx);
+            quote!(#item_index => sp::Some(#e),)
+        })
+        .collect::<Vec<_>>();
+
     let mut item_geometry_branch = component
         .geometries
         .iter()
@@ -896,6 +921,12 @@ fn generate_sub_component(
         supported_accessibility_actions_branch.push(quote!(
             #local_tree_index => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(0),
         ));
+        item_property_dependencies_branch.push(quote!(
+            (#local_tree_index, _) => #sub_compo_field.apply_pin(_self).item_property_dependencies(0, property),
+        ));
+        item_current_state_branch.push(quote!(
+            #local_tree_index => #sub_compo_field.apply_pin(_self).item_current_state(0),
+        ));
         if sub_items_count > 1 {
             let range_begin = local_index_of_first_child;
             let range_end = range_begin + sub_items_count - 2 + sub.ty.repeater_count();
@@ -914,6 +945,15 @@ fn generate_sub_component(
             supported_accessibility_actions_branch.push(quote!(
                 #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).supported_accessibility_actions(index - #range_begin + 1),
             ));
+            item_element_infos_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_element_infos(index - #range_begin + 1),
+            ));
+            item_property_dependencies_branch.push(quote!(
+                (#range_begin..=#range_end, _) => #sub_compo_field.apply_pin(_self).item_property_dependencies(index - #range_begin + 1, property),
+            ));
+            item_current_state_branch.push(quote!(
+                #range_begin..=#range_end => #sub_compo_field.apply_pin(_self).item_current_state(index - #range_begin + 1),
+            ));
         }
 
         sub_component_names.push(field_name);
@@ -1117,6 +1157,32 @@ fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: u32) ->
                 }
             }
 
+            fn item_element_infos(self: ::core::pin::Pin<&Self>, index: u32) -> sp::Option<sp::SharedString> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#item_element_infos_branch)*
+                    _ => sp::None,
+                }
+            }
+
+            fn item_property_dependencies(self: ::core::pin::Pin<&Self>, index: u32, property: &str) -> sp::Option<sp::SharedVector<sp::SharedString>> {
+                #![allow(unused)]
+                let _self = self;
+                match (index, property) {
+                    #(#item_property_dependencies_branch)*
+                    _ => sp::None,
+                }
+            }
+
+            fn item_current_state(self: ::core::pin::Pin<&Self>, index: u32) -> sp::Option<sp::SharedString> {
+                #![allow(unused)]
+                let _self = self;
+                match index {
+                    #(#item_current_state_branch)*
+                    _ => sp::None,
+                }
+            }
 
             #(#declared_functions)*
         }
@@ -1575,6 +1641,46 @@ fn supported_accessibility_actions(self: ::core::pin::Pin<&Self>, index: u32) ->
                 self.supported_accessibility_actions(index)
             }
 
+            fn item_element_infos(
+                self: ::core::pin::Pin<&Self>,
+                index: u32,
+                result: &mut sp::SharedString,
+            ) -> bool {
+                if let Some(infos) = self.item_element_infos(index) {
+                    *result = infos;
+                    true
+                } else {
+                    false
+                }
+            }
+
+            fn item_property_dependencies(
+                self: ::core::pin::Pin<&Self>,
+                index: u32,
+                property: &sp::SharedString,
+                result: &mut sp::SharedVector<sp::SharedString>,
+            ) -> bool {
+                if let Some(dependencies) = self.item_property_dependencies(index, property) {
+                    *result = dependencies;
+                    true
+                } else {
+                    false
+                }
+            }
+
+            fn item_current_state(
+                self: ::core::pin::Pin<&Self>,
+                index: u32,
+                result: &mut sp::SharedString,
+            ) -> bool {
+                if let Some(state) = self.item_current_state(index) {
+                    *result = state;
+                    true
+                } else {
+                    false
+                }
+            }
+
             fn window_adapter(
                 self: ::core::pin::Pin<&Self>,
                 do_create: bool,
@@ -97,6 +97,10 @@ pub struct CompilerConfiguration {
 
     /// C++ namespace
     pub cpp_namespace: Option<String>,
+
+    /// Generate debug information for the elements, such as their type and id, used by the
+    /// testing API to look up elements. (Enabled with the `SLINT_EMIT_DEBUG_INFO` environment variable)
+    pub debug_info: bool,
 }
 
 impl CompilerConfiguration {
@@ -157,6 +161,8 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             _ => None,
         };
 
+        let debug_info = std::env::var_os("SLINT_EMIT_DEBUG_INFO").is_some();
+
         Self {
             embed_resources,
             include_paths: Default::default(),
@@ -170,6 +176,7 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             enable_component_containers,
             translation_domain: None,
             cpp_namespace,
+            debug_info,
         }
     }
 }
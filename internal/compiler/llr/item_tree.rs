@@ -252,6 +252,18 @@ pub struct SubComponent {
     /// Maps (item_index, property) to an expression
     pub accessible_prop: BTreeMap<(u32, String), MutExpression>,
 
+    /// Maps item index to the type and id of the element, as returned by `Element::element_infos`.
+    /// Only filled when compiling with debug info.
+    pub element_infos: BTreeMap<u32, String>,
+
+    /// Maps (item_index, property) to the properties read by the binding of that property, as
+    /// in `Element::debug_property_dependencies`. Only filled when compiling with debug info.
+    pub property_dependencies: BTreeMap<(u32, String), Vec<String>>,
+
+    /// Maps item index to an expression that returns the name of the current state of the
+    /// element, as in `Element::debug_current_state`. Only filled when compiling with debug info.
+    pub current_states: BTreeMap<u32, MutExpression>,
+
     pub prop_analysis: HashMap<PropertyReference, PropAnalysis>,
 }
 
@@ -369,6 +381,9 @@ pub fn for_each_expression<'a>(
             for e in sc.accessible_prop.values() {
                 visitor(e, ctx);
             }
+            for e in sc.current_states.values() {
+                visitor(e, ctx);
+            }
             for i in sc.geometries.iter().flatten() {
                 visitor(i, ctx);
             }
@@ -9,11 +9,15 @@
 use crate::llr::item_tree::*;
 use crate::namedreference::NamedReference;
 use crate::object_tree::{Component, ElementRc, PropertyVisibility};
+use crate::CompilerConfiguration;
 use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
-pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
-    let mut state = LoweringState::default();
+pub fn lower_to_item_tree(
+    component: &Rc<Component>,
+    compiler_config: &CompilerConfiguration,
+) -> PublicComponent {
+    let mut state = LoweringState { debug_info: compiler_config.debug_info, ..Default::default() };
 
     let mut globals = Vec::new();
     for g in &component.used_types.borrow().globals {
@@ -53,6 +57,7 @@ pub fn lower_to_item_tree(component: &Rc<Component>) -> PublicComponent {
 
 #[derive(Default)]
 pub struct LoweringState {
+    debug_info: bool,
     global_properties: HashMap<NamedReference, PropertyReference>,
     sub_components: HashMap<ByAddress<Rc<Component>>, LoweredSubComponent>,
 }
@@ -200,12 +205,16 @@ fn lower_sub_component(
         layout_info_h: super::Expression::BoolLiteral(false).into(),
         layout_info_v: super::Expression::BoolLiteral(false).into(),
         accessible_prop: Default::default(),
+        element_infos: Default::default(),
+        property_dependencies: Default::default(),
+        current_states: Default::default(),
         prop_analysis: Default::default(),
     };
     let mut mapping = LoweredSubComponentMapping::default();
     let mut repeated = vec![];
     let mut component_container_data = vec![];
     let mut accessible_prop = Vec::new();
+    let mut current_states = Vec::new();
 
     if let Some(parent) = component.parent_element.upgrade() {
         // Add properties for the model data and index
@@ -312,6 +321,20 @@ fn lower_sub_component(
             }
             _ => unreachable!(),
         };
+        if state.debug_info {
+            let element_infos = elem.element_infos();
+            if !element_infos.is_empty() {
+                sub_component.element_infos.insert(*elem.item_index.get().unwrap(), element_infos);
+            }
+            for (prop, dependencies) in &elem.debug_property_dependencies {
+                sub_component
+                    .property_dependencies
+                    .insert((*elem.item_index.get().unwrap(), prop.clone()), dependencies.clone());
+            }
+            if let Some(nr) = &elem.debug_current_state {
+                current_states.push((*elem.item_index.get().unwrap(), nr.clone()));
+            }
+        }
         for (key, nr) in &elem.accessibility_props.0 {
             // TODO: we also want to split by type (role/string/...)
             let enum_value =
@@ -471,6 +494,13 @@ fn lower_sub_component(
         })
         .collect();
 
+    sub_component.current_states = current_states
+        .into_iter()
+        .map(|(idx, nr)| {
+            (idx, super::Expression::PropertyReference(ctx.map_property_reference(&nr)).into())
+        })
+        .collect();
+
     crate::object_tree::recurse_elem(&component.root_element, &(), &mut |element, _| {
         let elem = element.borrow();
         if elem.repeated.is_some() {
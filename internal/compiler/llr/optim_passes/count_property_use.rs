@@ -96,6 +96,9 @@ pub fn count_property_use(root: &PublicComponent) {
         for b in sc.accessible_prop.values() {
             b.borrow().visit_recursive(&mut |e| visit_expression(e, ctx))
         }
+        for b in sc.current_states.values() {
+            b.borrow().visit_recursive(&mut |e| visit_expression(e, ctx))
+        }
         for i in sc.geometries.iter().filter_map(Option::as_ref) {
             i.borrow().visit_recursive(&mut |e| visit_expression(e, ctx))
         }
@@ -658,6 +658,15 @@ pub struct Element {
     ///
     /// The order in the list is first the parent, and then the removed children.
     pub debug: Vec<(syntax_nodes::Element, Option<crate::layout::Layout>)>,
+
+    /// For each property binding of this element, the properties that its expression reads, as
+    /// they were written in the .slint file. Only collected when compiling with debug info.
+    pub debug_property_dependencies: BTreeMap<String, Vec<String>>,
+
+    /// A string property that holds the name of the current state of this element, or an empty
+    /// string when no state applies. Only created by the lower_states pass when compiling with
+    /// debug info.
+    pub debug_current_state: Option<NamedReference>,
 }
 
 impl Spanned for Element {
@@ -1694,6 +1703,55 @@ pub fn sub_component(&self) -> Option<&Rc<Component>> {
             None
         }
     }
+
+    /// Returns the type and the id of this element as written in the .slint file, for use by
+    /// the testing API.
+    ///
+    /// There is one line for this element and for each element that was merged into it
+    /// (by inlining, or because it is the root of the base component), in the same order as
+    /// [`Self::debug`]. Each line is either `TypeName`, or `TypeName;ComponentName::id` if the
+    /// element has an id (see [`element_debug_info`]).
+    pub fn element_infos(&self) -> String {
+        let mut nodes = self.debug.iter().map(|(node, _)| node.clone()).collect::<Vec<_>>();
+        let mut base_type = self.base_type.clone();
+        while let ElementType::Component(component) = base_type {
+            let root = component.root_element.borrow();
+            nodes.extend(root.debug.iter().map(|(node, _)| node.clone()));
+            base_type = root.base_type.clone();
+        }
+
+        nodes
+            .iter()
+            .map(|node| match element_debug_info(node) {
+                (type_name, Some(id)) => format!("{type_name};{id}"),
+                (type_name, None) => type_name,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Returns the type name and the qualified id of the element declared by `node`, as written in
+/// the .slint file.
+///
+/// The qualified id is `ComponentName::id`, or `ComponentName::root` for the root element of a
+/// component. It is None for other elements that don't have an id.
+pub fn element_debug_info(node: &syntax_nodes::Element) -> (String, Option<String>) {
+    let type_name = node
+        .QualifiedName()
+        .map(|qn| QualifiedTypeName::from_node(qn).to_string())
+        .unwrap_or_default();
+    let id = node.parent().and_then(|parent| match parent.kind() {
+        SyntaxKind::SubElement => parser::identifier_text(&parent),
+        SyntaxKind::Component => Some("root".into()),
+        _ => None,
+    });
+    let component_name = std::iter::successors(node.parent(), |n| n.parent())
+        .find(|n| n.kind() == SyntaxKind::Component)
+        .and_then(|n| n.child_node(SyntaxKind::DeclaredIdentifier))
+        .and_then(|n| parser::identifier_text(&n));
+    let qualified_id = id.zip(component_name).map(|(id, component)| format!("{component}::{id}"));
+    (type_name, qualified_id)
 }
 
 /// Apply default property values defined in `builtins.slint` to the element.
@@ -2148,6 +2206,12 @@ pub fn visit_all_named_references_in_element(
     accessibility_props.0.iter_mut().for_each(|(_, x)| vis(x));
     elem.borrow_mut().accessibility_props = accessibility_props;
 
+    let mut debug_current_state = elem.borrow_mut().debug_current_state.take();
+    if let Some(nr) = debug_current_state.as_mut() {
+        vis(nr);
+    }
+    elem.borrow_mut().debug_current_state = debug_current_state;
+
     let geometry_props = elem.borrow_mut().geometry_props.take();
     if let Some(mut geometry_props) = geometry_props {
         vis(&mut geometry_props.x);
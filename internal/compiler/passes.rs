@@ -11,6 +11,7 @@
 mod collect_custom_fonts;
 mod collect_globals;
 mod collect_init_code;
+mod collect_property_dependencies;
 mod collect_structs_and_enums;
 mod collect_subcomponents;
 mod compile_paths;
@@ -97,7 +98,12 @@ pub async fn run_passes(
             &style_metrics,
             diag,
         );
-        lower_states::lower_states(component, &doc.local_registry, diag);
+        lower_states::lower_states(
+            component,
+            &doc.local_registry,
+            type_loader.compiler_config.debug_info,
+            diag,
+        );
         lower_text_input_interface::lower_text_input_interface(component);
     }
 
@@ -276,6 +282,9 @@ pub fn run_import_passes(
 ) {
     infer_aliases_types::resolve_aliases(doc, diag);
     resolving::resolve_expressions(doc, type_loader, diag);
+    if type_loader.compiler_config.debug_info {
+        collect_property_dependencies::collect_property_dependencies(doc);
+    }
     focus_handling::replace_forward_focus_bindings_with_focus_functions(doc, diag);
     check_expressions::check_expressions(doc, diag);
     purity_check::purity_check(doc, diag);
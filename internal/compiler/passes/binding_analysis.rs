@@ -162,6 +162,9 @@ fn analyze_element(
     for nr in elem.borrow().accessibility_props.0.values() {
         process_property(&PropertyPath::from(nr.clone()), context, reverse_aliases, diag);
     }
+    if let Some(nr) = elem.borrow().debug_current_state.clone() {
+        process_property(&PropertyPath::from(nr), context, reverse_aliases, diag);
+    }
     if let Some(g) = elem.borrow().geometry_props.as_ref() {
         process_property(&g.x.clone().into(), context, reverse_aliases, diag);
         process_property(&g.y.clone().into(), context, reverse_aliases, diag);
@@ -0,0 +1,51 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.2 OR LicenseRef-Slint-commercial
+
+//! This pass fills `Element::debug_property_dependencies`.
+//!
+//! It must run right after resolving, so that the dependencies are the ones written in the
+//! .slint file, before any other pass adds or removes references.
+
+use crate::expression_tree::Expression;
+use crate::namedreference::NamedReference;
+use crate::object_tree::{element_debug_info, recurse_elem, Document};
+
+pub fn collect_property_dependencies(doc: &Document) {
+    for component in &doc.inner_components {
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            let dependencies = elem
+                .borrow()
+                .bindings
+                .iter()
+                .filter_map(|(prop, binding)| {
+                    let mut dependencies = Vec::new();
+                    binding.borrow().expression.visit_recursive(&mut |e| {
+                        if let Expression::PropertyReference(nr) = e {
+                            let name = dependency_name(nr);
+                            if !dependencies.contains(&name) {
+                                dependencies.push(name);
+                            }
+                        }
+                    });
+                    (!dependencies.is_empty()).then(|| (prop.clone(), dependencies))
+                })
+                .collect();
+            elem.borrow_mut().debug_property_dependencies = dependencies;
+        })
+    }
+}
+
+/// Returns `ComponentName::id.property` for properties of elements that have an id, and
+/// `TypeName.property` for the others.
+fn dependency_name(nr: &NamedReference) -> String {
+    let element = nr.element();
+    let element = element.borrow();
+    let owner = match element.debug.first() {
+        Some((node, _)) => match element_debug_info(node) {
+            (_, Some(id)) => id,
+            (type_name, None) => type_name,
+        },
+        None => element.base_type.to_string(),
+    };
+    format!("{owner}.{}", nr.name())
+}
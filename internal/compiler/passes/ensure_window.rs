@@ -52,6 +52,8 @@ pub fn ensure_window(
         item_index: Default::default(),
         item_index_of_first_children: Default::default(),
         debug: std::mem::take(&mut win_elem_mut.debug),
+        debug_property_dependencies: Default::default(),
+        debug_current_state: Default::default(),
 
         inline_depth: 0,
         is_legacy_syntax: false,
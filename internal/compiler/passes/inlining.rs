@@ -134,6 +134,18 @@ fn inline_element(
 
     elem_mut.children = new_children;
     elem_mut.debug.extend_from_slice(&inlined_component.root_element.borrow().debug);
+    for (prop, dependencies) in &inlined_component.root_element.borrow().debug_property_dependencies
+    {
+        // The bindings set where the element is used take precedence over the ones of the root
+        elem_mut
+            .debug_property_dependencies
+            .entry(prop.clone())
+            .or_insert_with(|| dependencies.clone());
+    }
+    if elem_mut.debug_current_state.is_none() {
+        elem_mut.debug_current_state =
+            inlined_component.root_element.borrow().debug_current_state.clone();
+    }
 
     if let ElementType::Component(c) = &mut elem_mut.base_type {
         if c.parent_element.upgrade().is_some() {
@@ -268,6 +280,8 @@ fn duplicate_element_with_mapping(
         repeated: elem.repeated.clone(),
         is_component_placeholder: elem.is_component_placeholder,
         debug: elem.debug.clone(),
+        debug_property_dependencies: elem.debug_property_dependencies.clone(),
+        debug_current_state: elem.debug_current_state.clone(),
         enclosing_component: Rc::downgrade(root_component),
         states: elem.states.clone(),
         transitions: elem
@@ -17,25 +17,27 @@
 pub fn lower_states(
     component: &Rc<Component>,
     tr: &crate::typeregister::TypeRegister,
+    debug_info: bool,
     diag: &mut BuildDiagnostics,
 ) {
     let state_info_type = tr.lookup("StateInfo");
     assert!(matches!(state_info_type, Type::Struct { name: Some(_), .. }));
     recurse_elem(&component.root_element, &(), &mut |elem, _| {
-        lower_state_in_element(elem, &state_info_type, diag)
+        lower_state_in_element(elem, &state_info_type, debug_info, diag)
     });
 }
 
 fn lower_state_in_element(
     root_element: &ElementRc,
     state_info_type: &Type,
+    debug_info: bool,
     diag: &mut BuildDiagnostics,
 ) {
     if root_element.borrow().states.is_empty() {
         return;
     }
     let has_transitions = !root_element.borrow().transitions.is_empty();
-    let state_property_name = compute_state_property_name(root_element);
+    let state_property_name = compute_state_property_name(root_element, "state");
     let state_property =
         Expression::PropertyReference(NamedReference::new(root_element, &state_property_name));
     let state_property_ref = if has_transitions {
@@ -114,25 +116,37 @@ fn lower_state_in_element(
         .bindings
         .insert(state_property_name, RefCell::new(state_value.into()));
 
-    lower_transitions_in_element(
+    let animated_properties = lower_transitions_in_element(
         root_element,
         state_property,
-        states_id,
+        &states_id,
         affected_properties,
         diag,
     );
+
+    if debug_info {
+        declare_current_state_name(
+            root_element,
+            &state_property_ref,
+            &states_id,
+            animated_properties,
+        );
+    }
 }
 
+/// Returns the properties that the transitions animate
 fn lower_transitions_in_element(
     elem: &ElementRc,
     state_property: Expression,
-    states_id: HashMap<String, i32>,
+    states_id: &HashMap<String, i32>,
     affected_properties: HashSet<NamedReference>,
     diag: &mut BuildDiagnostics,
-) {
+) -> Vec<NamedReference> {
     let transitions = std::mem::take(&mut elem.borrow_mut().transitions);
     let mut props =
         HashMap::<NamedReference, (SourceLocation, Vec<TransitionPropertyAnimation>)>::new();
+    // The keys of `props`, in the order of the transitions
+    let mut animated_properties = Vec::new();
     for transition in transitions {
         let state = states_id.get(&transition.state_id).unwrap_or_else(|| {
             diag.push_error(
@@ -161,7 +175,14 @@ fn lower_transitions_in_element(
                 is_out: transition.is_out,
                 animation,
             };
-            props.entry(p).or_insert_with(|| (span.clone(), vec![])).1.push(t);
+            props
+                .entry(p.clone())
+                .or_insert_with(|| {
+                    animated_properties.push(p);
+                    (span.clone(), vec![])
+                })
+                .1
+                .push(t);
         }
     }
     for (ne, (span, animations)) in props {
@@ -180,11 +201,63 @@ fn lower_transitions_in_element(
             );
         }
     }
+    animated_properties
+}
+
+/// Declares the property referenced by `Element::debug_current_state`, which maps the integer id
+/// of the current state back to its name.
+///
+/// The binding also reads the `animated_properties` of the transitions, so that evaluating it
+/// while a transition runs evaluates their animations as well. This is how the testing backend
+/// finds out whether the element is in a transition.
+fn declare_current_state_name(
+    root_element: &ElementRc,
+    state_property_ref: &Expression,
+    states_id: &HashMap<String, i32>,
+    animated_properties: Vec<NamedReference>,
+) {
+    let mut states = states_id.iter().collect::<Vec<_>>();
+    states.sort_by_key(|(_, id)| **id);
+    let name_value = states.into_iter().rev().fold(
+        Expression::StringLiteral(String::new()),
+        |false_expr, (name, id)| Expression::Condition {
+            condition: Box::new(Expression::BinaryExpression {
+                lhs: Box::new(state_property_ref.clone()),
+                rhs: Box::new(Expression::NumberLiteral(*id as _, Unit::None)),
+                op: '=',
+            }),
+            true_expr: Box::new(Expression::StringLiteral(name.clone())),
+            false_expr: Box::new(false_expr),
+        },
+    );
+    let name_value = if animated_properties.is_empty() {
+        name_value
+    } else {
+        Expression::CodeBlock(
+            animated_properties
+                .into_iter()
+                .map(Expression::PropertyReference)
+                .chain(std::iter::once(name_value))
+                .collect(),
+        )
+    };
+    let property_name = compute_state_property_name(root_element, "state-name");
+    root_element.borrow_mut().property_declarations.insert(
+        property_name.clone(),
+        PropertyDeclaration { property_type: Type::String, ..PropertyDeclaration::default() },
+    );
+    root_element
+        .borrow_mut()
+        .bindings
+        .insert(property_name.clone(), RefCell::new(name_value.into()));
+    let current_state = NamedReference::new(root_element, &property_name);
+    root_element.borrow_mut().debug_current_state = Some(current_state);
 }
 
-/// Returns a suitable unique name for the "state" property
-fn compute_state_property_name(root_element: &ElementRc) -> String {
-    let mut property_name = "state".to_owned();
+/// Returns a suitable unique name for a property derived from `base_name`, such as the "state"
+/// property, by appending dashes until no property of `root_element` has that name
+fn compute_state_property_name(root_element: &ElementRc, base_name: &str) -> String {
+    let mut property_name = base_name.to_owned();
     while root_element.borrow().lookup_property(property_name.as_ref()).property_type
         != Type::Invalid
     {
@@ -37,6 +37,8 @@ fn create_repeater_components(component: &Rc<Component>) {
                 repeated: None,
                 is_component_placeholder: false,
                 debug: elem.debug.clone(),
+                debug_property_dependencies: std::mem::take(&mut elem.debug_property_dependencies),
+                debug_current_state: elem.debug_current_state.take(),
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
@@ -12,7 +12,8 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
-use core::cell::Cell;
+use alloc::rc::Rc;
+use core::cell::{Cell, RefCell};
 
 /// A Callback that can be connected to a handler.
 ///
@@ -56,6 +57,70 @@ pub fn has_handler(&self) -> bool {
     pub fn set_handler(&self, mut f: impl FnMut(&Arg) -> Ret + 'static) {
         self.handler.set(Some(Box::new(move |a: &Arg, r: &mut Ret| *r = f(a))));
     }
+
+    /// Set a function to be called with the argument each time the callback is called, before
+    /// the current handler, if any.
+    ///
+    /// Unlike [`Self::set_handler`], this keeps the current handler and its return value. The
+    /// observer is called until the returned [`CallbackObserver`] is dropped or passed to
+    /// [`Self::remove_observer`], or until a new handler is set.
+    pub fn observe(&self, f: impl FnMut(&Arg) + 'static) -> CallbackObserver<Arg, Ret>
+    where
+        Arg: 'static,
+        Ret: 'static,
+    {
+        let state = Rc::new(ObserverState {
+            observer: RefCell::new(Some(Box::new(f))),
+            handler: RefCell::new(self.handler.take()),
+        });
+        let s = state.clone();
+        let wrapper: Box<dyn FnMut(&Arg, &mut Ret)> = Box::new(move |a: &Arg, r: &mut Ret| {
+            if let Some(f) = s.observer.borrow_mut().as_mut() {
+                f(a);
+            }
+            if let Some(h) = s.handler.borrow_mut().as_mut() {
+                h(a, r);
+            }
+        });
+        let wrapper_ptr = &*wrapper as *const dyn FnMut(&Arg, &mut Ret) as *const ();
+        self.handler.set(Some(wrapper));
+        CallbackObserver { state, wrapper_ptr }
+    }
+
+    /// Stops calling an observer installed with [`Self::observe`]. If no other observer or
+    /// handler was set since, this also restores the handler that the observer wrapped.
+    pub fn remove_observer(&self, observer: CallbackObserver<Arg, Ret>) {
+        observer.state.observer.take();
+        let current = self.handler.take();
+        let is_current = current.as_ref().map_or(false, |h| {
+            &**h as *const dyn FnMut(&Arg, &mut Ret) as *const () == observer.wrapper_ptr
+        });
+        if is_current {
+            self.handler.set(observer.state.handler.take());
+        } else {
+            self.handler.set(current);
+        }
+    }
+}
+
+struct ObserverState<Arg: ?Sized, Ret> {
+    observer: RefCell<Option<Box<dyn FnMut(&Arg)>>>,
+    /// The handler that was set when the observer was installed
+    handler: RefCell<Option<Box<dyn FnMut(&Arg, &mut Ret)>>>,
+}
+
+/// Returned by [`Callback::observe`]. The observer is no longer called once this is dropped.
+#[must_use]
+pub struct CallbackObserver<Arg: ?Sized, Ret> {
+    state: Rc<ObserverState<Arg, Ret>>,
+    /// Identifies the handler installed by [`Callback::observe`]
+    wrapper_ptr: *const (),
+}
+
+impl<Arg: ?Sized, Ret> Drop for CallbackObserver<Arg, Ret> {
+    fn drop(&mut self) {
+        self.state.observer.take();
+    }
 }
 
 #[test]
@@ -73,6 +138,32 @@ struct Component {
     assert!(c.pressed.get());
 }
 
+#[test]
+fn callback_observe_test() {
+    let callback = Callback::<(i32,), i32>::default();
+    let observed = Rc::new(core::cell::Cell::new(0));
+    let o = observed.clone();
+    let first = callback.observe(move |(x,)| o.set(o.get() + x));
+    assert_eq!(callback.call(&(2,)), 0);
+    callback.set_handler(|(x,)| x * 10);
+    let o = observed.clone();
+    let second = callback.observe(move |(x,)| o.set(o.get() + x));
+    assert_eq!(callback.call(&(3,)), 30);
+    assert_eq!(observed.get(), 5);
+
+    // The first observer was replaced by the handler, and the second stops with the removal
+    callback.remove_observer(first);
+    callback.remove_observer(second);
+    assert_eq!(callback.call(&(4,)), 40);
+    assert_eq!(observed.get(), 5);
+
+    let o = observed.clone();
+    let dropped = callback.observe(move |(x,)| o.set(o.get() + x));
+    drop(dropped);
+    assert_eq!(callback.call(&(5,)), 50);
+    assert_eq!(observed.get(), 5);
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi {
     #![allow(unsafe_code)]
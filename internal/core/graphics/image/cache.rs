@@ -179,3 +179,9 @@ pub fn replace_cached_image(key: ImageCacheKey, value: ImageInner) {
     let _ =
         IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().0.put_with_weight(key, value));
 }
+
+/// Removes all the images from the cache. Images that are still in use stay valid, but the next
+/// attempt to load them will decode them again.
+pub fn clear() {
+    IMAGE_CACHE.with(|global_cache| global_cache.borrow_mut().0.clear());
+}
@@ -13,7 +13,7 @@
 use crate::lengths::{LogicalPoint, LogicalRect};
 use crate::slice::Slice;
 use crate::window::WindowAdapterRc;
-use crate::SharedString;
+use crate::{SharedString, SharedVector};
 use core::pin::Pin;
 use vtable::*;
 
@@ -128,6 +128,34 @@ pub struct ItemTreeVTable {
         item_index: u32,
     ) -> SupportedAccessibilityAction,
 
+    /// Returns the debug information of the element at `item_index` via the `result`:
+    /// its type and id as written in the .slint file (see `ItemRc::element_infos`).
+    /// Returns false if the information was not generated.
+    pub item_element_infos: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        result: &mut SharedString,
+    ) -> bool,
+
+    /// Returns via the `result` the properties that are read by the binding of `property` of the
+    /// element at `item_index`, as written in the .slint file. Returns false if there is no such
+    /// binding, or if the debug information was not generated.
+    pub item_property_dependencies: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        property: &SharedString,
+        result: &mut SharedVector<SharedString>,
+    ) -> bool,
+
+    /// Returns via the `result` the name of the current state of the element at `item_index`, or
+    /// an empty string if no state applies. Returns false if the element has no states, or if the
+    /// debug information was not generated.
+    pub item_current_state: extern "C" fn(
+        core::pin::Pin<VRef<ItemTreeVTable>>,
+        item_index: u32,
+        result: &mut SharedString,
+    ) -> bool,
+
     /// Returns a Window, creating a fresh one if `do_create` is true.
     pub window_adapter: extern "C" fn(
         core::pin::Pin<VRef<ItemTreeVTable>>,
@@ -360,6 +388,43 @@ pub fn supported_accessibility_actions(&self) -> SupportedAccessibilityAction {
         comp_ref_pin.as_ref().supported_accessibility_actions(self.index)
     }
 
+    /// Returns the type and id of the elements that make up this item, one line per element in the
+    /// form `TypeName` or `TypeName;ComponentName::id`, starting with the outermost element.
+    ///
+    /// Returns None if the debug information was not generated for this item.
+    pub fn element_infos(&self) -> Option<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        let mut result = Default::default();
+        let ok = comp_ref_pin.as_ref().item_element_infos(self.index, &mut result);
+        ok.then_some(result)
+    }
+
+    /// Returns the properties that are read by the binding of `property` of this item, in the form
+    /// `ComponentName::id.property`.
+    ///
+    /// Returns None if there is no such binding, or if the debug information was not generated.
+    pub fn property_dependencies(&self, property: &str) -> Option<SharedVector<SharedString>> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        let mut result = Default::default();
+        let ok = comp_ref_pin.as_ref().item_property_dependencies(
+            self.index,
+            &property.into(),
+            &mut result,
+        );
+        ok.then_some(result)
+    }
+
+    /// Returns the name of the state that currently applies to this item, as declared in its
+    /// `states` block, or an empty string if no state applies.
+    ///
+    /// Returns None if the item has no states, or if the debug information was not generated.
+    pub fn current_state(&self) -> Option<SharedString> {
+        let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
+        let mut result = Default::default();
+        let ok = comp_ref_pin.as_ref().item_current_state(self.index, &mut result);
+        ok.then_some(result)
+    }
+
     pub fn geometry(&self) -> LogicalRect {
         let comp_ref_pin = vtable::VRc::borrow_pin(&self.item_tree);
         comp_ref_pin.as_ref().item_geometry(self.index)
@@ -1174,6 +1239,23 @@ fn supported_accessibility_actions(
         ) -> SupportedAccessibilityAction {
             unimplemented!("Not needed for this test")
         }
+
+        fn item_element_infos(self: core::pin::Pin<&Self>, _: u32, _: &mut SharedString) -> bool {
+            false
+        }
+
+        fn item_property_dependencies(
+            self: core::pin::Pin<&Self>,
+            _: u32,
+            _: &SharedString,
+            _: &mut SharedVector<SharedString>,
+        ) -> bool {
+            false
+        }
+
+        fn item_current_state(self: core::pin::Pin<&Self>, _: u32, _: &mut SharedString) -> bool {
+            false
+        }
     }
 
     crate::item_tree::ItemTreeVTable_static!(static TEST_COMPONENT_VT for TestItemTree);
@@ -6,11 +6,34 @@
 // cspell:ignore coord
 
 use crate::items::{DialogButtonRole, LayoutAlignment};
+#[cfg(all(
+    feature = "debug-counters",
+    not(feature = "std"),
+    feature = "unsafe-single-threaded"
+))]
+use crate::thread_local;
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
 pub use crate::items::Orientation;
 
+#[cfg(feature = "debug-counters")]
+thread_local! {
+    static SOLVE_COUNT: core::cell::Cell<u32> = core::cell::Cell::new(0)
+}
+
+/// Returns how many times [`solve_box_layout`] and [`solve_grid_layout`] were called in this
+/// thread. The testing backend uses it to count the layouts that a change causes to be solved.
+#[cfg(feature = "debug-counters")]
+pub fn solve_count() -> u32 {
+    SOLVE_COUNT.with(|count| count.get())
+}
+
+#[cfg(feature = "debug-counters")]
+fn increment_solve_count() {
+    SOLVE_COUNT.with(|count| count.set(count.get().wrapping_add(1)));
+}
+
 /// The constraint that applies to an item
 // Also, the field needs to be in alphabetical order because how the generated code sort fields for struct
 #[repr(C)]
@@ -440,6 +463,8 @@ pub struct GridLayoutCellData {
 
 /// return, an array which is of size `data.cells.len() * 2` which for each cell we give the pos, size
 pub fn solve_grid_layout(data: &GridLayoutData) -> SharedVector<Coord> {
+    #[cfg(feature = "debug-counters")]
+    increment_solve_count();
     let mut layout_data =
         grid_internal::to_layout_data(data.cells.as_slice(), data.spacing, Some(data.size));
 
@@ -505,6 +530,8 @@ pub struct BoxLayoutCellData {
 
 /// Solve a BoxLayout
 pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
+    #[cfg(feature = "debug-counters")]
+    increment_solve_count();
     let mut result = SharedVector::<Coord>::default();
     result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
 
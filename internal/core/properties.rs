@@ -225,6 +225,12 @@ fn drop(&mut self) {
 type DependencyListHead = dependency_tracker::DependencyListHead<*const BindingHolder>;
 type DependencyNode = dependency_tracker::DependencyNode<*const BindingHolder>;
 
+#[cfg(all(
+    feature = "debug-counters",
+    not(feature = "std"),
+    feature = "unsafe-single-threaded"
+))]
+use crate::thread_local;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use alloc::rc::Rc;
@@ -334,6 +340,23 @@ unsafe impl Sync for FakeThreadStorage {}
 static CURRENT_BINDING: unsafe_single_threaded::FakeThreadStorage =
     unsafe_single_threaded::FakeThreadStorage::new();
 
+#[cfg(feature = "debug-counters")]
+thread_local! {
+    static EVALUATION_COUNT: Cell<u32> = Cell::new(0)
+}
+
+/// Returns how many times the bindings of properties were evaluated in this thread.
+/// The testing backend uses it to count the bindings that a change causes to be evaluated again.
+#[cfg(feature = "debug-counters")]
+pub fn binding_evaluation_count() -> u32 {
+    EVALUATION_COUNT.with(|count| count.get())
+}
+
+/// The message of the panic when a property is accessed while it is already being accessed, for
+/// example from its own binding. The testing backend matches it to detect binding loops.
+#[doc(hidden)]
+pub const RECURSION_DETECTED_MESSAGE: &str = "Recursion detected";
+
 /// Evaluate a function, but do not register any property dependencies if that function
 /// get the value of properties
 pub fn evaluate_no_tracking<T>(f: impl FnOnce() -> T) -> T {
@@ -485,7 +508,7 @@ unsafe fn set_lock_flag(&self, set: bool) {
     /// Access the value.
     /// Panics if the function try to recursively access the value
     fn access<R>(&self, f: impl FnOnce(Option<Pin<&mut BindingHolder>>) -> R) -> R {
-        assert!(!self.lock_flag(), "Recursion detected");
+        assert!(!self.lock_flag(), "{}", RECURSION_DETECTED_MESSAGE);
         unsafe {
             self.set_lock_flag(true);
             scopeguard::defer! { self.set_lock_flag(false); }
@@ -500,7 +523,7 @@ fn access<R>(&self, f: impl FnOnce(Option<Pin<&mut BindingHolder>>) -> R) -> R {
     }
 
     fn remove_binding(&self) {
-        assert!(!self.lock_flag(), "Recursion detected");
+        assert!(!self.lock_flag(), "{}", RECURSION_DETECTED_MESSAGE);
         let val = self.handle.get();
         if val & 0b10 == 0b10 {
             unsafe {
@@ -574,7 +597,7 @@ fn set_binding_impl(&self, binding: *mut BindingHolder) {
     }
 
     fn dependencies(&self) -> *mut DependencyListHead {
-        assert!(!self.lock_flag(), "Recursion detected");
+        assert!(!self.lock_flag(), "{}", RECURSION_DETECTED_MESSAGE);
         if (self.handle.get() & 0b10) != 0 {
             self.access(|binding| binding.unwrap().dependencies.as_ptr() as *mut DependencyListHead)
         } else {
@@ -590,6 +613,8 @@ unsafe fn update<T>(&self, value: *mut T) {
                 if binding.dirty.get() {
                     // clear all the nodes so that we can start from scratch
                     binding.dep_nodes.set(Default::default());
+                    #[cfg(feature = "debug-counters")]
+                    EVALUATION_COUNT.with(|count| count.set(count.get().wrapping_add(1)));
                     let r = (binding.vtable.evaluate)(
                         binding.as_mut().get_unchecked_mut() as *mut BindingHolder,
                         value as *mut (),
@@ -1020,6 +1020,11 @@ pub fn close_popup(&self) {
         }
     }
 
+    /// Returns the component of the currently active popup, if any.
+    pub fn active_popup_component(&self) -> Option<ItemTreeRc> {
+        self.active_popup.borrow().as_ref().map(|popup| popup.component.clone())
+    }
+
     /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
     pub fn close_popup_on_click(&self) -> bool {
         self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
@@ -1383,6 +1383,59 @@ pub fn send_keyboard_string_sequence(
             &WindowInner::from_pub(comp.window()).window_adapter(),
         );
     }
+
+    /// Returns the type of the property `property` of the element declared with the id
+    /// `element_id`, or None if there is no such element or property.
+    ///
+    /// The id must be qualified with the name of the component it was declared in, for example
+    /// `MyComponent::my-button`. The root element of a component has the id `MyComponent::root`.
+    /// Properties that are never used are optimized away and are not found.
+    pub fn element_property_type(
+        comp: &super::ComponentInstance,
+        element_id: &str,
+        property: &str,
+    ) -> Option<super::ValueType> {
+        crate::dynamic_item_tree::element_property_type(&comp.inner, element_id, property)
+    }
+
+    /// Panics if the property `property` of the element declared with the id `element_id`
+    /// doesn't exist or doesn't have the type `expected`.
+    #[track_caller]
+    pub fn assert_property_type(
+        comp: &super::ComponentInstance,
+        element_id: &str,
+        property: &str,
+        expected: super::ValueType,
+    ) {
+        match element_property_type(comp, element_id, property) {
+            Some(ty) if ty == expected => (),
+            Some(ty) => {
+                panic!("{element_id}.{property} has the type {ty:?}, expected {expected:?}")
+            }
+            None => panic!("{element_id} has no property {property}"),
+        }
+    }
+
+    /// Compiles `source` with a new [`ComponentCompiler`](super::ComponentCompiler) and panics
+    /// if the compiler reports an error. Warnings are allowed.
+    ///
+    /// Like [`ComponentCompiler::build_from_source`](super::ComponentCompiler::build_from_source),
+    /// this function is only asynchronous when the source imports files through a file loader,
+    /// so it can be run with a very simple executor, such as the one provided by the `spin_on`
+    /// crate.
+    pub async fn assert_no_compile_errors(source: &str) {
+        let mut compiler = super::ComponentCompiler::default();
+        compiler.build_from_source(source.into(), Default::default()).await;
+        let errors = compiler
+            .diagnostics()
+            .iter()
+            .filter(|d| d.level() == super::DiagnosticLevel::Error)
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            panic!("The source has compile errors:\n{}", errors.join("\n"));
+        }
+    }
 }
 
 #[test]
@@ -1433,6 +1486,63 @@ fn component_definition_properties() {
     assert_eq!(instance.get_property("underscores-and-dashes-preserved"), Ok(Value::Number(88.)));
 }
 
+#[test]
+fn element_property_type() {
+    i_slint_backend_testing::init_no_event_loop();
+    let mut compiler = ComponentCompiler::default();
+    compiler.set_style("fluent".into());
+    let comp_def = spin_on::spin_on(
+        compiler.build_from_source(
+            r#"
+    component Card {
+        in property <[string]> lines;
+        label := Text { text: lines[0]; }
+    }
+    export component Dummy {
+        in-out property <int> count;
+        card := Card { }
+        my_rect := Rectangle { background: red; }
+    }"#
+            .into(),
+            "".into(),
+        ),
+    )
+    .unwrap();
+    let instance = comp_def.create().unwrap();
+
+    use testing::{assert_property_type, element_property_type};
+    assert_property_type(&instance, "Dummy::root", "count", ValueType::Number);
+    assert_property_type(&instance, "Dummy::card", "lines", ValueType::Model);
+    assert_property_type(&instance, "Card::root", "lines", ValueType::Model);
+    assert_property_type(&instance, "Card::label", "text", ValueType::String);
+    assert_property_type(&instance, "Card::label", "color", ValueType::Brush);
+    assert_property_type(&instance, "Dummy::my-rect", "background", ValueType::Brush);
+    assert_property_type(&instance, "Dummy::my_rect", "visible", ValueType::Bool);
+    assert_eq!(element_property_type(&instance, "Dummy::card", "no-such-property"), None);
+    assert_eq!(element_property_type(&instance, "Dummy::no-such-element", "x"), None);
+}
+
+#[test]
+fn assert_no_compile_errors() {
+    i_slint_backend_testing::init_no_event_loop();
+    use testing::assert_no_compile_errors;
+    spin_on::spin_on(assert_no_compile_errors(
+        "export component Dummy { in property <int> count; Text { text: count; } }",
+    ));
+    // A component that is implicitly exported only produces a warning
+    spin_on::spin_on(assert_no_compile_errors("component Dummy { }"));
+    let result = std::panic::catch_unwind(|| {
+        spin_on::spin_on(assert_no_compile_errors(
+            "export component Dummy { Text { text: no-such-property; } }",
+        ))
+    });
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(|| {
+        spin_on::spin_on(assert_no_compile_errors("export component Dummy {"))
+    });
+    assert!(result.is_err());
+}
+
 #[test]
 fn component_definition_properties2() {
     i_slint_backend_testing::init_no_event_loop();
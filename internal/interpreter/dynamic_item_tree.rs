@@ -255,6 +255,31 @@ fn supported_accessibility_actions(
     ) -> SupportedAccessibilityAction {
         self.borrow().as_ref().supported_accessibility_actions(index)
     }
+
+    fn item_element_infos(
+        self: core::pin::Pin<&Self>,
+        index: u32,
+        result: &mut SharedString,
+    ) -> bool {
+        self.borrow().as_ref().item_element_infos(index, result)
+    }
+
+    fn item_property_dependencies(
+        self: core::pin::Pin<&Self>,
+        index: u32,
+        property: &SharedString,
+        result: &mut SharedVector<SharedString>,
+    ) -> bool {
+        self.borrow().as_ref().item_property_dependencies(index, property, result)
+    }
+
+    fn item_current_state(
+        self: core::pin::Pin<&Self>,
+        index: u32,
+        result: &mut SharedString,
+    ) -> bool {
+        self.borrow().as_ref().item_current_state(index, result)
+    }
 }
 
 i_slint_core::ItemTreeVTable_static!(static COMPONENT_BOX_VT for ErasedItemTreeBox);
@@ -1174,6 +1199,9 @@ macro_rules! match_enum_type {
         accessible_string_property,
         accessibility_action,
         supported_accessibility_actions,
+        item_element_infos,
+        item_property_dependencies,
+        item_current_state,
         window_adapter,
         drop_in_place,
         dealloc,
@@ -1620,6 +1648,46 @@ fn from(inner: ItemTreeBox<'id>) -> Self {
     }
 }
 
+/// Returns the type of `property` in the element that was declared with the qualified id
+/// `element_id`, as in `ComponentName::element-id`.
+pub(crate) fn element_property_type(
+    component: &ErasedItemTreeBox,
+    element_id: &str,
+    property: &str,
+) -> Option<crate::api::ValueType> {
+    generativity::make_guard!(guard);
+    let description = component.unerase(guard).description();
+    let element_id = parser::normalize_identifier(element_id);
+    let property = parser::normalize_identifier(property);
+    let mut result = None;
+    object_tree::recurse_elem_including_sub_components(
+        &description.original,
+        &(),
+        &mut |elem, _| {
+            if result.is_some()
+                || !elem.borrow().debug.iter().any(|(node, _)| {
+                    object_tree::element_debug_info(node).1.as_deref() == Some(element_id.as_str())
+                })
+            {
+                return;
+            }
+            let lookup = elem.borrow().lookup_property(&property);
+            if lookup.is_valid() {
+                result = Some(lookup.property_type.into());
+                return;
+            }
+            // The move_declarations pass moved the declarations to the root element
+            let enclosing_component = elem.borrow().enclosing_component.upgrade().unwrap();
+            let moved_name = format!("{}-{}", elem.borrow().id, property);
+            let lookup = enclosing_component.root_element.borrow().lookup_property(&moved_name);
+            if lookup.is_valid() {
+                result = Some(lookup.property_type.into());
+            }
+        },
+    );
+    result
+}
+
 pub fn get_repeater_by_name<'a, 'id>(
     instance_ref: InstanceRef<'a, '_>,
     name: &str,
@@ -1951,6 +2019,62 @@ extern "C" fn supported_accessibility_actions(
     val
 }
 
+extern "C" fn item_element_infos(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    result: &mut SharedString,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    *result = instance_ref.description.original_elements[item_index as usize]
+        .borrow()
+        .element_infos()
+        .into();
+    true
+}
+
+extern "C" fn item_property_dependencies(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    property: &SharedString,
+    result: &mut SharedVector<SharedString>,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let elem = instance_ref.description.original_elements[item_index as usize].borrow();
+    let Some(dependencies) = elem.debug_property_dependencies.get(property.as_str()) else {
+        return false;
+    };
+    *result = dependencies.iter().map(SharedString::from).collect();
+    true
+}
+
+extern "C" fn item_current_state(
+    component: ItemTreeRefPin,
+    item_index: u32,
+    result: &mut SharedString,
+) -> bool {
+    generativity::make_guard!(guard);
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let nr = instance_ref.description.original_elements[item_index as usize]
+        .borrow()
+        .debug_current_state
+        .clone();
+    let Some(nr) = nr else {
+        return false;
+    };
+    match crate::eval::load_property(instance_ref, &nr.element(), nr.name()) {
+        Ok(Value::String(s)) => {
+            *result = s;
+            true
+        }
+        _ => {
+            debug_assert!(false, "The current state name of the element isn't a string");
+            false
+        }
+    }
+}
+
 extern "C" fn window_adapter(
     component: ItemTreeRefPin,
     do_create: bool,
@@ -508,6 +508,29 @@ pub extern "C" fn slint_interpreter_component_instance_show(
     )
 }
 
+/// Returns the type of the property of the element declared with the given qualified id,
+/// see `testing::element_property_type`.
+#[no_mangle]
+pub extern "C" fn slint_interpreter_testing_element_property_type(
+    inst: &ErasedItemTreeBox,
+    element_id: Slice<u8>,
+    property: Slice<u8>,
+    out: &mut ValueType,
+) -> bool {
+    let (Ok(element_id), Ok(property)) =
+        (std::str::from_utf8(&element_id), std::str::from_utf8(&property))
+    else {
+        return false;
+    };
+    match crate::dynamic_item_tree::element_property_type(inst, element_id, property) {
+        Some(ty) => {
+            *out = ty;
+            true
+        }
+        None => false,
+    }
+}
+
 /// Instantiate an instance from a definition.
 ///
 /// The `out` must be uninitialized and is going to be initialized after the call
@@ -26,7 +26,8 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
     let mut compiler_config = CompilerConfiguration::new(output_format.clone());
     compiler_config.include_paths = include_paths;
     compiler_config.library_paths = library_paths;
-    let (root_component, diag, _) =
+    compiler_config.debug_info = true;
+    let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
     if diag.has_error() {
@@ -36,7 +37,12 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
 
     let mut generated_cpp: Vec<u8> = Vec::new();
 
-    generator::generate(output_format, &mut generated_cpp, &root_component)?;
+    generator::generate(
+        output_format,
+        &mut generated_cpp,
+        &root_component,
+        &loader.compiler_config,
+    )?;
 
     if diag.has_error() {
         let vec = diag.to_string_vec();
@@ -163,7 +163,7 @@ fn generate_source(
     compiler_config.enable_component_containers = true;
     compiler_config.style = Some("fluent".to_string());
     compiler_config.scale_factor = scale_factor.into();
-    let (root_component, diag, _) =
+    let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
     if diag.has_error() {
@@ -176,6 +176,11 @@ fn generate_source(
         diag.print();
     }
 
-    generator::generate(generator::OutputFormat::Rust, output, &root_component)?;
+    generator::generate(
+        generator::OutputFormat::Rust,
+        output,
+        &root_component,
+        &loader.compiler_config,
+    )?;
     Ok(())
 }